@@ -0,0 +1,101 @@
+//! Throughput of the PubMed parsers on the test fixtures.
+//!
+//! The records of the fixtures are repeated into a large document, which is then
+//! parsed by each parser. Run with `cargo bench`, or
+//! `cargo bench --features rayon` to include the parallel parsers.
+
+extern crate entrez_rs;
+
+#[cfg(feature = "rayon")]
+use std::fs;
+#[cfg(feature = "rayon")]
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use entrez_rs::parser::pubmed::{PubmedArticleReader, PubmedArticleSet};
+
+const FIXTURES: [&str; 2] = [
+    include_str!("../tests/fixtures/pubmed_articles.xml"),
+    include_str!("../tests/fixtures/pubmed_book_articles.xml")
+];
+const COPIES: usize = 500;
+const ROUNDS: u32 = 3;
+
+fn records(xml: &str) -> &str {
+    let start = xml.find("<PubmedArticleSet>").unwrap() + "<PubmedArticleSet>".len();
+    let end = xml.rfind("</PubmedArticleSet>").unwrap();
+    &xml[start..end]
+}
+
+fn document() -> String {
+    let records = FIXTURES.iter().map(|f| records(f)).collect::<String>();
+    format!("<PubmedArticleSet>{}</PubmedArticleSet>", records.repeat(COPIES))
+}
+
+// A directory of generated files, removed when dropped, even on panic.
+#[cfg(feature = "rayon")]
+struct TempDir(PathBuf);
+
+#[cfg(feature = "rayon")]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn report(name: &str, bytes: usize, mut parse: impl FnMut() -> usize) {
+    let mut records = 0;
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        records = parse();
+        best = best.min(start.elapsed());
+    }
+
+    let secs = best.as_secs_f64();
+    println!(
+        "{:<32} {:>8} records {:>10.0} records/s {:>8.1} MB/s",
+        name,
+        records,
+        records as f64 / secs,
+        bytes as f64 / secs / 1e6
+    );
+}
+
+fn main() {
+    let xml = document();
+
+    report("PubmedArticleSet::read", xml.len(), || {
+        PubmedArticleSet::read(&xml).unwrap().articles.len()
+    });
+
+    report("PubmedArticleReader", xml.len(), || {
+        PubmedArticleReader::new(xml.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .len()
+    });
+
+    #[cfg(feature = "rayon")]
+    {
+        report("PubmedArticleSet::par_read", xml.len(), || {
+            PubmedArticleSet::par_read(&xml).unwrap().articles.len()
+        });
+
+        let dir = TempDir(std::env::temp_dir().join(format!("entrez-rs-bench-{}", std::process::id())));
+        fs::create_dir_all(&dir.0).unwrap();
+        let paths = (0..4)
+            .map(|i| {
+                let path = dir.0.join(format!("pubmed{}.xml", i));
+                fs::write(&path, &xml).unwrap();
+                path
+            })
+            .collect::<Vec<_>>();
+
+        report("PubmedArticleSet::par_read_paths", xml.len() * paths.len(), || {
+            PubmedArticleSet::par_read_paths(&paths)
+                .into_iter()
+                .map(|set| set.unwrap().articles.len())
+                .sum()
+        });
+    }
+}
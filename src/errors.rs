@@ -1,21 +1,71 @@
+/// A position in a parsed document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextPosition {
+    /// Offset in bytes from the start of the document.
+    pub byte: usize,
+    /// Line number, starting at 1.
+    pub line: u32,
+    /// Column number, starting at 1.
+    pub column: u32
+}
+
 #[derive(Debug)]
 pub enum ParsingErrorType {
+    #[cfg(any(feature = "parsers-esearch", feature = "parsers-pubmed"))]
     QXML(quick_xml::DeError),
-    RXML(roxmltree::Error)
+    #[cfg(feature = "parsers-pubmed")]
+    RXML(roxmltree::Error),
+    /// The XML reader failed, e.g. on a truncated document.
+    #[cfg(feature = "parsers-pubmed")]
+    Xml(quick_xml::Error),
+    /// The document has no root element, e.g. an empty response.
+    MissingRoot,
+    /// NCBI sent an `<ERROR>` element instead of results.
+    NCBIError {
+        message: String,
+        position: TextPosition
+    },
+    /// The root element is not the one the parser expects.
+    UnexpectedRoot {
+        expected: &'static str,
+        found: String,
+        position: TextPosition
+    },
+    /// An element the parser does not know, in strict mode.
+    UnknownElement {
+        path: String,
+        position: TextPosition
+    }
 }
 
 #[derive(Debug)]
 pub enum Error {
+    #[cfg(feature = "client")]
     ConnectionError(reqwest::Error),
-    ParsingError(ParsingErrorType)
+    ParsingError(ParsingErrorType),
+    IOError(std::io::Error),
+    ChecksumMismatch {
+        expected: String,
+        actual: String
+    },
+    /// The `.md5` companion file at this path contains no MD5 digest.
+    InvalidChecksumFile(std::path::PathBuf)
 }
 
+#[cfg(feature = "client")]
 impl From<reqwest::Error> for Error {
     fn from(re: reqwest::Error) -> Self {
         Error::ConnectionError(re)
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IOError(e)
+    }
+}
+
+#[cfg(any(feature = "parsers-esearch", feature = "parsers-pubmed"))]
 impl From<quick_xml::DeError> for Error {
     fn from(e: quick_xml::DeError) -> Self {
         Error::ParsingError(
@@ -24,6 +74,16 @@ impl From<quick_xml::DeError> for Error {
     }
 }
 
+#[cfg(feature = "parsers-pubmed")]
+impl From<quick_xml::Error> for Error {
+    fn from(e: quick_xml::Error) -> Self {
+        Error::ParsingError(
+            ParsingErrorType::Xml(e)
+        )
+    }
+}
+
+#[cfg(feature = "parsers-pubmed")]
 impl From<roxmltree::Error> for Error {
     fn from(e: roxmltree::Error) -> Self {
         Error::ParsingError(
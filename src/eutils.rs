@@ -1,10 +1,30 @@
 
 use std::fmt::{Display, Formatter};
 use std::default::Default;
+#[cfg(feature = "blocking")]
+use std::io::Write;
+#[cfg(feature = "blocking")]
+use std::thread;
+#[cfg(feature = "blocking")]
+use std::time::Duration;
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Response;
+#[cfg(feature = "blocking")]
+use super::progress::{ProgressEvent, ProgressObserver};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use super::instrument::RequestSpan;
 
 const BASE: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/";
+
+// NCBI allows 3 requests per second without an API key.
+#[cfg(feature = "blocking")]
+const REQUEST_INTERVAL: Duration = Duration::from_millis(340);
+#[cfg(feature = "blocking")]
+const MAX_RETRIES: u32 = 3;
+#[cfg(feature = "blocking")]
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
     
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DB {
     Pubmed
 }
@@ -26,9 +46,177 @@ pub enum XMLResult{
 pub trait Eutils {
     fn build_url(&self) -> String;
 
+    #[cfg(feature = "blocking")]
     fn run(&self) -> Result<String, reqwest::Error>;
-    
 
+    /// Sends the request and returns the response without reading the body.
+    /// The returned [Response] implements [Read](std::io::Read), so large
+    /// results can be consumed as they arrive instead of being buffered.
+    /// Error statuses such as `429` or `5xx` are returned as errors.
+    ///
+    /// With the `tracing` feature, the request span ends when the headers are
+    /// received: its latency excludes the body and its size is the
+    /// `Content-Length`, missing for chunked responses.
+    #[cfg(feature = "blocking")]
+    fn run_reader(&self) -> Result<Response, reqwest::Error> {
+        let url = self.build_url();
+        let span = RequestSpan::new(&url);
+        let res = get(&url, &span)?
+            .error_for_status()
+            .map_err(|e| span.fail(e))?;
+
+        span.finish(res.content_length());
+        Ok(res)
+    }
+
+    /// Sends the request and streams the response body into `writer`,
+    /// returning the number of bytes written. Nothing is written when the
+    /// response has an error status such as `429` or `5xx`.
+    #[cfg(feature = "blocking")]
+    fn run_to_writer<W: Write + ?Sized>(&self, writer: &mut W) -> Result<u64, reqwest::Error>
+    where Self: Sized {
+        let url = self.build_url();
+        let span = RequestSpan::new(&url);
+        let bytes = get(&url, &span)?
+            .error_for_status()
+            .map_err(|e| span.fail(e))?
+            .copy_to(writer)
+            .map_err(|e| span.fail(e))?;
+
+        span.finish(Some(bytes));
+        Ok(bytes)
+    }
+
+    /// Like [run_to_writer](Eutils::run_to_writer), but retries transient failures
+    /// and reports received bytes and retries to `progress`.
+    #[cfg(feature = "blocking")]
+    fn run_to_writer_with_progress<W: Write + ?Sized>(&self, writer: &mut W, progress: &mut dyn ProgressObserver) -> Result<u64, reqwest::Error>
+    where Self: Sized {
+        let url = self.build_url();
+        let span = RequestSpan::new(&url);
+        let bytes = send(&url, progress, &span)?
+            .copy_to(&mut ProgressWriter { inner: writer, progress })
+            .map_err(|e| span.fail(e))?;
+
+        span.finish(Some(bytes));
+        Ok(bytes)
+    }
+}
+
+/// Sends a single GET request, recording its status on `span`.
+#[cfg(feature = "blocking")]
+fn get(url: &str, span: &RequestSpan) -> Result<Response, reqwest::Error> {
+    let res = span.in_scope(|| reqwest::blocking::get(url))
+        .map_err(|e| span.fail(e))?;
+
+    span.record_status(res.status().as_u16());
+    Ok(res)
+}
+
+/// Sends a GET request, retrying timeouts, connection failures,
+/// `429 Too Many Requests` and server errors up to [MAX_RETRIES] times.
+#[cfg(feature = "blocking")]
+fn send(url: &str, progress: &mut dyn ProgressObserver, span: &RequestSpan) -> Result<Response, reqwest::Error> {
+    let (res, retries) = retry(progress, thread::sleep, is_transient, || {
+        let res = span.in_scope(|| reqwest::blocking::get(url));
+        if let Ok(r) = &res {
+            span.record_status(r.status().as_u16());
+        }
+
+        res.and_then(|r| r.error_for_status())
+    });
+
+    span.record_retries(retries);
+    res.map_err(|e| span.fail(e))
+}
+
+/// Runs `request` until it succeeds, fails with an error that is not transient
+/// or has been retried [MAX_RETRIES] times, reporting each retry and its backoff
+/// to `progress` and passing the backoff to `wait`.
+/// Returns the last result and the number of retries.
+#[cfg(feature = "blocking")]
+pub(crate) fn retry<T, E: Display>(
+    progress: &mut dyn ProgressObserver,
+    mut wait: impl FnMut(Duration),
+    is_transient: impl Fn(&E) -> bool,
+    mut request: impl FnMut() -> Result<T, E>
+) -> (Result<T, E>, u32) {
+    let mut attempt = 0;
+
+    loop {
+        match request() {
+            Err(e) if attempt < MAX_RETRIES && is_transient(&e) => {
+                attempt += 1;
+                progress.on_event(&ProgressEvent::Retry {
+                    attempt,
+                    error: e.to_string()
+                });
+
+                let backoff = RETRY_BACKOFF * attempt;
+                progress.on_event(&ProgressEvent::ThrottleWait(backoff));
+                wait(backoff);
+            },
+            res => return (res, attempt)
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+fn is_transient(e: &reqwest::Error) -> bool {
+    e.is_timeout()
+        || e.is_connect()
+        || e.status().is_some_and(|s| s.as_u16() == 429 || s.is_server_error())
+}
+
+/// Forwards writes to `inner`, reporting every chunk as [ProgressEvent::BytesReceived].
+#[cfg(feature = "blocking")]
+struct ProgressWriter<'w, W: ?Sized> {
+    inner: &'w mut W,
+    progress: &'w mut dyn ProgressObserver
+}
+
+#[cfg(feature = "blocking")]
+impl<'w, W: Write + ?Sized> Write for ProgressWriter<'w, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.progress.on_event(&ProgressEvent::BytesReceived(n as u64));
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Sends the request without blocking and returns the response body.
+/// Requires the `async` feature and a tokio runtime.
+#[cfg(feature = "async")]
+pub async fn run_async<E: Eutils + ?Sized>(eutil: &E) -> Result<String, reqwest::Error> {
+    let url = eutil.build_url();
+    let span = RequestSpan::new(&url);
+    let res = span.instrument(reqwest::get(&url)).await
+        .map_err(|e| span.fail(e))?;
+
+    span.record_status(res.status().as_u16());
+    let text = span.instrument(res.text()).await
+        .map_err(|e| span.fail(e))?;
+
+    span.finish(Some(text.len() as u64));
+    Ok(text)
+}
+
+/// Sends the request without blocking and returns the response, whose body
+/// can be consumed incrementally with [chunk](reqwest::Response::chunk).
+#[cfg(feature = "async")]
+pub async fn run_async_response<E: Eutils + ?Sized>(eutil: &E) -> Result<reqwest::Response, reqwest::Error> {
+    let url = eutil.build_url();
+    let span = RequestSpan::new(&url);
+    let res = span.instrument(reqwest::get(&url)).await
+        .map_err(|e| span.fail(e))?;
+
+    span.record_status(res.status().as_u16());
+    span.finish(res.content_length());
+    Ok(res)
 }
 
 #[derive(Debug, PartialEq)]
@@ -102,7 +290,7 @@ impl<'a> Eutils for ESearch<'a> {
             url_str.push_str(&(format!("&query_key={}", i)));
         } 
         if let Some(i) = &self.retstart {
-            url_str.push_str(&(format!("&WebEnv={}", i)));
+            url_str.push_str(&(format!("&retstart={}", i)));
         } 
         if let Some(i) = &self.retmax {
             url_str.push_str(&(format!("&retmax={}", i)));
@@ -136,18 +324,25 @@ impl<'a> Eutils for ESearch<'a> {
         return url_str;
     }
 
+    #[cfg(feature = "blocking")]
     fn run(&self) -> Result<String, reqwest::Error> {
         
         let url = self.build_url();
-        let res = reqwest::blocking::get(&url)?
-                  .text();
+        let span = RequestSpan::new(&url);
+        let res = get(&url, &span)?
+                  .text()
+                  .map_err(|e| span.fail(e));
+
+        if let Ok(text) = &res {
+            span.finish(Some(text.len() as u64));
+        }
 
         res
         
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct EFetch<'a> {
     pub db: DB, 
     pub id_list: Vec<&'a str>,
@@ -192,6 +387,107 @@ impl<'a> EFetch<'a> {
             ..Default::default()
         }
     }
+
+    /// Fetches the records in batches of `batch_size`, streaming each batch into `writer`
+    /// and reporting progress to `progress`. Returns the number of bytes written.
+    ///
+    /// When `id_list` is not empty, the IDs are split into batches and `total` is ignored.
+    /// Otherwise the `webenv` and `query_key` history is paged with `retstart` and `retmax`
+    /// until `total` records (usually the `count` of an ESearch result) have been fetched.
+    /// Requests are spaced to respect the NCBI rate limits.
+    #[cfg(feature = "blocking")]
+    pub fn run_batches<W: Write + ?Sized>(&self, total: u64, batch_size: u64, writer: &mut W, progress: &mut dyn ProgressObserver) -> Result<u64, reqwest::Error> {
+        let batches = self.batches(total, batch_size);
+
+        run_planned(&batches, progress, thread::sleep, |batch, progress| {
+            let span = RequestSpan::new(&batch.url);
+            let mut response = send(&batch.url, progress, &span)?;
+            let bytes = response.copy_to(&mut ProgressWriter { inner: writer, progress })
+                .map_err(|e| span.fail(e))?;
+
+            span.finish(Some(bytes));
+            Ok(bytes)
+        })
+    }
+
+    /// Splits the download described by [run_batches](EFetch::run_batches) into requests.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn batches(&self, total: u64, batch_size: u64) -> Vec<Batch> {
+        let batch_size = batch_size.max(1);
+        let mut batches = Vec::new();
+
+        if self.id_list.is_empty() {
+            let mut retstart = 0;
+            while retstart < total {
+                let size = batch_size.min(total - retstart);
+                let (start_str, max_str) = (retstart.to_string(), size.to_string());
+
+                let mut page: EFetch = self.clone();
+                page.retstart = Some(&start_str);
+                page.retmax = Some(&max_str);
+                batches.push(Batch { retstart, size, url: page.build_url() });
+
+                retstart += size;
+            }
+        } else {
+            for (i, ids) in self.id_list.chunks(batch_size as usize).enumerate() {
+                let mut page = self.clone();
+                page.id_list = ids.to_vec();
+                batches.push(Batch {
+                    retstart: i as u64 * batch_size,
+                    size: ids.len() as u64,
+                    url: page.build_url()
+                });
+            }
+        }
+
+        batches
+    }
+}
+
+/// One request of a batched download: `size` records starting at `retstart`.
+#[cfg(feature = "blocking")]
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Batch {
+    pub retstart: u64,
+    pub size: u64,
+    pub url: String
+}
+
+/// Fetches `batches` in order with `fetch`, which returns the size of each body,
+/// and reports them to `progress`. Between two requests, [REQUEST_INTERVAL] is
+/// reported and passed to `wait`. Returns the total number of bytes.
+#[cfg(feature = "blocking")]
+pub(crate) fn run_planned<E>(
+    batches: &[Batch],
+    progress: &mut dyn ProgressObserver,
+    mut wait: impl FnMut(Duration),
+    mut fetch: impl FnMut(&Batch, &mut dyn ProgressObserver) -> Result<u64, E>
+) -> Result<u64, E> {
+    progress.on_event(&ProgressEvent::TotalCount(
+        batches.iter().map(|batch| batch.size).sum()
+    ));
+
+    let mut written = 0;
+    for (index, batch) in batches.iter().enumerate() {
+        if index > 0 {
+            progress.on_event(&ProgressEvent::ThrottleWait(REQUEST_INTERVAL));
+            wait(REQUEST_INTERVAL);
+        }
+
+        progress.on_event(&ProgressEvent::BatchStarted {
+            index,
+            retstart: batch.retstart,
+            size: batch.size
+        });
+
+        let bytes = fetch(batch, progress)?;
+        written += bytes;
+
+        progress.on_event(&ProgressEvent::BatchFinished { index, bytes });
+    }
+
+    Ok(written)
 }
 
 
@@ -208,7 +504,7 @@ impl<'a> Eutils for EFetch<'a> {
             url_string.push_str(&(format!("&query_key={}", i)));
              } 
             if let Some(i) = &self.retstart {
-            url_string.push_str(&(format!("&WebEnv={}", i)));
+            url_string.push_str(&(format!("&retstart={}", i)));
             } 
             if let Some(i) = &self.retmax {
             url_string.push_str(&(format!("&retmax={}", i)));
@@ -236,11 +532,18 @@ impl<'a> Eutils for EFetch<'a> {
             } 
              url_string
     }
+    #[cfg(feature = "blocking")]
     fn run(&self) -> Result<String, reqwest::Error> {
         
         let url = self.build_url();
-        let res = reqwest::blocking::get(&url)?
-                  .text();
+        let span = RequestSpan::new(&url);
+        let res = get(&url, &span)?
+                  .text()
+                  .map_err(|e| span.fail(e));
+
+        if let Ok(text) = &res {
+            span.finish(Some(text.len() as u64));
+        }
         
         res
         
@@ -0,0 +1,226 @@
+//! `tracing` spans for E-utility requests and parser entry points.
+//!
+//! With the `tracing` feature disabled every type here is a no-op,
+//! so the request and parser code can be instrumented without `cfg` attributes.
+#![cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+
+#[cfg(all(feature = "tracing", any(feature = "blocking", feature = "async")))]
+use std::fmt::Display;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(all(feature = "tracing", any(feature = "blocking", feature = "async", feature = "parsers-esearch", feature = "parsers-pubmed")))]
+use std::time::Instant;
+#[cfg(all(feature = "tracing", feature = "async"))]
+use tracing::Instrument;
+
+// Query parameters whose values must never end up in logs.
+#[cfg(any(all(feature = "tracing", any(feature = "blocking", feature = "async")), test))]
+const CREDENTIALS: [&str; 2] = ["api_key", "email"];
+
+/// Replaces the values of credential query parameters in `url` with `REDACTED`.
+#[cfg(any(all(feature = "tracing", any(feature = "blocking", feature = "async")), test))]
+pub(crate) fn redact_url(url: &str) -> String {
+    let (base, query) = match url.find('?') {
+        Some(i) => (&url[..=i], &url[i + 1..]),
+        None => return url.to_string()
+    };
+
+    let params: Vec<String> = query
+        .split('&')
+        .map(|param| match param.split_once('=') {
+            Some((key, _)) if CREDENTIALS.contains(&key) => format!("{}=REDACTED", key),
+            _ => param.to_string()
+        })
+        .collect();
+
+    format!("{}{}", base, params.join("&"))
+}
+
+/// Extracts the utility name, database and number of IDs from an E-utility URL.
+#[cfg(any(all(feature = "tracing", any(feature = "blocking", feature = "async")), test))]
+pub(crate) fn url_fields(url: &str) -> (&str, &str, usize) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let utility = path
+        .rsplit('/')
+        .next()
+        .unwrap_or("")
+        .trim_end_matches(".fcgi");
+
+    let mut db = "";
+    let mut id_count = 0;
+    for (key, value) in query.split('&').filter_map(|p| p.split_once('=')) {
+        match key {
+            "db" => db = value,
+            "id" => id_count = value.split(',').filter(|id| !id.is_empty()).count(),
+            _ => ()
+        }
+    }
+
+    (utility, db, id_count)
+}
+
+/// A span covering one HTTP request to an E-utility.
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) struct RequestSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    start: Instant
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl RequestSpan {
+    pub(crate) fn new(url: &str) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let (utility, db, id_count) = url_fields(url);
+            let span = tracing::info_span!(
+                "entrez_request",
+                utility,
+                db,
+                id_count,
+                url = %redact_url(url),
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                retries = tracing::field::Empty,
+                bytes = tracing::field::Empty,
+                error = tracing::field::Empty
+            );
+
+            RequestSpan {
+                span,
+                start: Instant::now()
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        RequestSpan {}
+    }
+
+    #[cfg(feature = "blocking")]
+    pub(crate) fn in_scope<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        #[cfg(feature = "tracing")]
+        return self.span.in_scope(f);
+        #[cfg(not(feature = "tracing"))]
+        f()
+    }
+
+    /// Runs `future` inside the span.
+    #[cfg(all(feature = "async", feature = "tracing"))]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        future.instrument(self.span.clone())
+    }
+
+    #[cfg(all(feature = "async", not(feature = "tracing")))]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> F {
+        future
+    }
+
+    pub(crate) fn record_status(&self, status: u16) {
+        #[cfg(feature = "tracing")]
+        self.span.record("status", status);
+    }
+
+    #[cfg(feature = "blocking")]
+    pub(crate) fn record_retries(&self, retries: u32) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("retries", retries);
+            if retries > 0 {
+                self.span.in_scope(|| tracing::warn!(retries, "request retried"));
+            }
+        }
+    }
+
+    /// Records the latency since the span was created and, when known, the body size.
+    pub(crate) fn finish(&self, bytes: Option<u64>) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("latency_ms", self.start.elapsed().as_millis() as u64);
+            if let Some(bytes) = bytes {
+                self.span.record("bytes", bytes);
+            }
+        }
+    }
+
+    /// Records `error` as the outcome of the request, with the latency so far,
+    /// and hands it back for `map_err`.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn fail<E: Display>(&self, error: E) -> E {
+        self.span.record("latency_ms", self.start.elapsed().as_millis() as u64);
+        self.span.record("error", tracing::field::display(&error));
+        self.span.in_scope(|| tracing::warn!(%error, "request failed"));
+        error
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn fail<E>(&self, error: E) -> E {
+        error
+    }
+}
+
+/// A span covering one call to a parser entry point.
+#[cfg(any(feature = "parsers-esearch", feature = "parsers-pubmed"))]
+pub(crate) struct ParseSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    start: Instant
+}
+
+#[cfg(any(feature = "parsers-esearch", feature = "parsers-pubmed"))]
+impl ParseSpan {
+    pub(crate) fn new(parser: &'static str, bytes: usize) -> Self {
+        Self::with_bytes(parser, Some(bytes))
+    }
+
+    /// A span for input read as a stream, whose size is recorded by
+    /// [finish_stream](ParseSpan::finish_stream) once it has been read.
+    #[cfg(feature = "parsers-pubmed")]
+    pub(crate) fn streaming(parser: &'static str) -> Self {
+        Self::with_bytes(parser, None)
+    }
+
+    fn with_bytes(parser: &'static str, bytes: Option<usize>) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::info_span!(
+                "entrez_parse",
+                parser,
+                bytes,
+                records = tracing::field::Empty,
+                parse_ms = tracing::field::Empty
+            );
+
+            ParseSpan {
+                span,
+                start: Instant::now()
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        ParseSpan {}
+    }
+
+    pub(crate) fn in_scope<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        #[cfg(feature = "tracing")]
+        return self.span.in_scope(f);
+        #[cfg(not(feature = "tracing"))]
+        f()
+    }
+
+    /// Records the number of parsed records and the time spent since the span was created.
+    pub(crate) fn finish(&self, records: usize) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("records", records);
+            self.span.record("parse_ms", self.start.elapsed().as_millis() as u64);
+        }
+    }
+
+    /// Like [finish](ParseSpan::finish), also recording the size of the stream.
+    #[cfg(feature = "parsers-pubmed")]
+    pub(crate) fn finish_stream(&self, records: usize, bytes: usize) {
+        #[cfg(feature = "tracing")]
+        self.span.record("bytes", bytes);
+        self.finish(records);
+    }
+}
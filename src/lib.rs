@@ -14,19 +14,42 @@
 //! Currently available wrappers are:
 //!  - [ESearch](eutils::ESearch)
 //!  - [EFetch](eutils::EFetch) 
+//!
+//! Long-running batched downloads report their progress through the
+//! [progress] module.
 //! 
 //! ### Parsers:
 //! The [parser] module contains the parsers.
 //! Currently available parsers are:
 //!  - [esearch](parser::esearch)
 //!  - [pubmed](parser::pubmed)
+//!
+//! # Features
+//! Networking and the parsers are behind cargo features, so the parsers and
+//! the URL builders can be used without an HTTP stack (e.g. on `wasm32-unknown-unknown`):
+//!  - `blocking` *(default)*: blocking [run](eutils::Eutils::run) methods.
+//!  - `async`: non-blocking [run_async](eutils::run_async), for use on a tokio runtime.
+//!  - `client`: the `reqwest` client shared by `blocking` and `async`.
+//!  - `parsers-esearch` *(default)*: the [esearch](parser::esearch) parser.
+//!  - `parsers-pubmed` *(default)*: the [pubmed](parser::pubmed) and
+//!    [baseline](parser::baseline) parsers.
+//!  - `chrono`: conversions of PubMed [dates](parser::pubmed::PartialDate) to `chrono::NaiveDate`.
+//!  - `rayon`: [par_read](parser::pubmed::PubmedArticleSet::par_read) and
+//!    [par_read_paths](parser::pubmed::PubmedArticleSet::par_read_paths), parsing
+//!    PubMed records and files in parallel.
+//!  - `tracing`: `tracing` spans for every E-utility request (utility, db, ID count,
+//!    redacted URL, status, latency, retries and bytes or error), blocking or async,
+//!    and every parser call (record count and parse time).
 pub mod eutils;
 pub mod parser;
 pub mod errors;
+pub mod progress;
+mod instrument;
 
 #[cfg(test)]
 mod tests {
     use super::eutils::*;
+    use super::instrument::{redact_url, url_fields};
     
     #[test]
     fn build_esearch() {
@@ -49,4 +72,137 @@ mod tests {
         
         println!("{:#?}", &url);
     }
+
+    #[test]
+    fn build_efetch_history_page() {
+        let url = EFetch {
+            webenv: Some("MCID_1"),
+            query_key: Some("1"),
+            retstart: Some("500"),
+            retmax: Some("250"),
+            .. EFetch::new(DB::Pubmed, vec![])
+        }.build_url();
+
+        assert!(url.contains("&WebEnv=MCID_1"));
+        assert!(url.contains("&retstart=500"));
+        assert!(url.contains("&retmax=250"));
+    }
+
+    #[test]
+    fn redact_credentials() {
+        let url = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/efetch.fcgi?db=pubmed&id=1,2,3&api_key=secret&email=me@example.org";
+
+        assert_eq!(
+            redact_url(url),
+            "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/efetch.fcgi?db=pubmed&id=1,2,3&api_key=REDACTED&email=REDACTED"
+        );
+        assert_eq!(url_fields(url), ("efetch", "pubmed", 3));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[derive(Default)]
+    struct Recorder(Vec<super::progress::ProgressEvent>);
+
+    #[cfg(feature = "blocking")]
+    impl super::progress::ProgressObserver for Recorder {
+        fn on_event(&mut self, event: &super::progress::ProgressEvent) {
+            self.0.push(event.clone());
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn plan_batches() {
+        let history = EFetch {
+            webenv: Some("MCID_1"),
+            query_key: Some("1"),
+            .. EFetch::new(DB::Pubmed, vec![])
+        };
+        let pages = history.batches(5, 2);
+
+        assert_eq!(
+            pages.iter().map(|b| (b.retstart, b.size)).collect::<Vec<_>>(),
+            vec![(0, 2), (2, 2), (4, 1)]
+        );
+        assert!(pages[2].url.contains("&retstart=4&retmax=1"));
+        assert!(history.batches(0, 2).is_empty());
+
+        let ids = EFetch::new(DB::Pubmed, vec!["1", "2", "3", "4", "5"]).batches(100, 2);
+
+        assert_eq!(
+            ids.iter().map(|b| (b.retstart, b.size)).collect::<Vec<_>>(),
+            vec![(0, 2), (2, 2), (4, 1)]
+        );
+        assert!(ids[2].url.contains("&id=5&"));
+        assert!(!ids[2].url.contains("retstart"));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn batch_progress_events() {
+        use std::time::Duration;
+        use super::progress::ProgressEvent::*;
+
+        let pages = EFetch {
+            webenv: Some("MCID_1"),
+            query_key: Some("1"),
+            .. EFetch::new(DB::Pubmed, vec![])
+        }.batches(5, 2);
+
+        let mut recorder = Recorder::default();
+        let mut waits = Vec::new();
+        let written = run_planned(&pages, &mut recorder, |d| waits.push(d), |batch, progress| {
+            progress.on_event(&BytesReceived(batch.size * 10));
+            Ok::<_, ()>(batch.size * 10)
+        });
+
+        assert_eq!(written, Ok(50));
+        assert_eq!(waits.len(), 2);
+        assert_eq!(recorder.0, vec![
+            TotalCount(5),
+            BatchStarted { index: 0, retstart: 0, size: 2 },
+            BytesReceived(20),
+            BatchFinished { index: 0, bytes: 20 },
+            ThrottleWait(waits[0]),
+            BatchStarted { index: 1, retstart: 2, size: 2 },
+            BytesReceived(20),
+            BatchFinished { index: 1, bytes: 20 },
+            ThrottleWait(waits[1]),
+            BatchStarted { index: 2, retstart: 4, size: 1 },
+            BytesReceived(10),
+            BatchFinished { index: 2, bytes: 10 }
+        ]);
+
+        let mut recorder = Recorder::default();
+        let failed = run_planned(&pages, &mut recorder, |_| (), |batch, _| {
+            if batch.retstart == 2 { Err("connection reset") } else { Ok(1) }
+        });
+
+        assert_eq!(failed, Err("connection reset"));
+        assert_eq!(recorder.0.last(), Some(&BatchStarted { index: 1, retstart: 2, size: 2 }));
+
+        let mut recorder = Recorder::default();
+        let mut errors = vec!["503", "timeout"].into_iter();
+        let (res, retries) = retry(&mut recorder, |_| (), |e: &&str| *e != "404", || {
+            errors.next().map_or(Ok(42), Err)
+        });
+
+        assert_eq!((res, retries), (Ok(42), 2));
+        assert_eq!(recorder.0, vec![
+            Retry { attempt: 1, error: "503".to_string() },
+            ThrottleWait(Duration::from_secs(1)),
+            Retry { attempt: 2, error: "timeout".to_string() },
+            ThrottleWait(Duration::from_secs(2))
+        ]);
+
+        let mut recorder = Recorder::default();
+        let (res, retries) = retry(&mut recorder, |_| (), |e: &&str| *e != "404", || Err::<(), _>("404"));
+
+        assert_eq!((res, retries), (Err("404"), 0));
+        assert!(recorder.0.is_empty());
+
+        let (res, retries) = retry(&mut Recorder::default(), |_| (), |_: &&str| true, || Err::<(), _>("503"));
+
+        assert_eq!((res, retries), (Err("503"), 3));
+    }
 }
\ No newline at end of file
@@ -1,2 +1,6 @@
+#[cfg(feature = "parsers-pubmed")]
 pub mod pubmed;
-pub mod esearch;
\ No newline at end of file
+#[cfg(feature = "parsers-esearch")]
+pub mod esearch;
+#[cfg(feature = "parsers-pubmed")]
+pub mod baseline;
\ No newline at end of file
@@ -0,0 +1,108 @@
+//! Utilities to read the PubMed baseline and update files distributed by the NLM.
+
+//! # Description
+//! The annual baseline and the daily update files are published as gzip compressed
+//! [PubmedArticleSet](super::pubmed::PubmedArticleSet) documents
+//! (`pubmed25n0001.xml.gz`), each with an `.md5` companion file:
+//! ``` text
+//! MD5(pubmed25n0001.xml.gz)= 0f4ba4b2e2b4a1e5c7a26f1d6f8a0e92
+//! ```
+//! [open] returns a reader over a local file, decompressing it when it is gzipped,
+//! and [verify_md5] checks a file against its companion.
+//!
+//! # Example
+//!
+//!  ```no_run
+//!   use entrez_rs::parser::baseline;
+//!   use entrez_rs::parser::pubmed::{PubmedArticleSet};
+//!
+//!   let path = "pubmed25n0001.xml.gz";
+//!
+//!   baseline::verify_md5(path).expect("Checksum error");
+//!
+//!   let parsed = PubmedArticleSet::read_path(path)
+//!                .expect("Parsing error");
+//!
+//!   println!("{}", parsed.articles.len());
+//!   ```
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use flate2::bufread::MultiGzDecoder;
+use super::super::errors::{Error};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Wraps `reader` in a gzip decoder if its content starts with the gzip magic bytes,
+/// otherwise returns it unchanged.
+pub fn decompress<'a, R: BufRead + 'a>(mut reader: R) -> Result<Box<dyn BufRead + 'a>, Error> {
+    let is_gzip = reader.fill_buf()?.starts_with(&GZIP_MAGIC);
+
+    if is_gzip {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Opens the file at `path` for reading, transparently decompressing gzip content.
+pub fn open<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>, Error> {
+    let file = File::open(path)?;
+    decompress(BufReader::new(file))
+}
+
+/// Computes the lowercase hex MD5 digest of everything read from `reader`.
+pub fn md5_hex<R: Read>(mut reader: R) -> Result<String, Error> {
+    let mut context = md5::Context::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        context.consume(&buf[..n]);
+    }
+
+    Ok(format!("{:x}", context.compute()))
+}
+
+/// Extracts the digest from the content of an `.md5` companion file.
+/// Both the `MD5(file)= digest` and the `digest  file` layouts are accepted.
+pub fn parse_md5_file(content: &str) -> Option<String> {
+    content
+        .split(|c: char| c.is_whitespace() || c == '=')
+        .find(|t| t.len() == 32 && t.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(|t| t.to_ascii_lowercase())
+}
+
+/// Returns the path of the `.md5` companion of `path`.
+pub fn md5_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let mut companion = path.as_ref().as_os_str().to_owned();
+    companion.push(".md5");
+    PathBuf::from(companion)
+}
+
+/// Checks the raw (still compressed) bytes of the file at `path` against the digest
+/// stored in its `.md5` companion.
+pub fn verify_md5<P: AsRef<Path>>(path: P) -> Result<(), Error> {
+    let companion = md5_path(&path);
+    let mut content = String::new();
+    File::open(&companion)?.read_to_string(&mut content)?;
+
+    let expected = match parse_md5_file(&content) {
+        Some(digest) => digest,
+        None => return Err(Error::InvalidChecksumFile(companion))
+    };
+    let actual = md5_hex(File::open(&path)?)?;
+
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Error::ChecksumMismatch {
+            expected,
+            actual
+        })
+    }
+}
@@ -17,6 +17,7 @@
 //! # Example
 //! 
 //!  ```
+//!   # #[cfg(feature = "blocking")] {
 //!   use entrez_rs::eutils::{Eutils, ESearch, DB};
 //!   use entrez_rs::parser::esearch::{ESearchResult};
 //! 
@@ -30,6 +31,7 @@
 //!                .expect("Parsing error");
 //! 
 //!   assert_eq!(parsed.id_list.ids.len(), 20);
+//!   # }
 //!   ```
 
 
@@ -37,6 +39,7 @@ extern crate serde;
 extern crate quick_xml;
 use serde::{Deserialize};
 use quick_xml::de::{from_str, DeError};
+use super::super::instrument::ParseSpan;
 
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -104,6 +107,10 @@ pub struct ESearchResult {
 
 impl ESearchResult {
     pub fn read(xml: &str) -> Result<ESearchResult, DeError> {
-        from_str(xml)
+        let span = ParseSpan::new("esearch", xml.len());
+        let res: ESearchResult = span.in_scope(|| from_str(xml))?;
+
+        span.finish(res.id_list.ids.len());
+        Ok(res)
     }
 }
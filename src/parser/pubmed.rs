@@ -18,11 +18,17 @@
 //! </PubmedArticleSet>
 //!  ```
 //! The [read](PubmedArticleSet::read) function will parse this XML into a [PubmedArticleSet]
-//! struct.
+//! struct. Sets may also contain `<PubmedBookArticle>` records (books and book chapters
+//! such as GeneReviews), which are kept in document order next to journal articles
+//! as [PubmedRecord]s.
+//!
+//! Elements the parser does not know are skipped. Use [read_with](PubmedArticleSet::read_with)
+//! and [ParseOptions] to list them, or to fail on them in strict mode.
 //! 
 //! # Example
 //! 
 //!  ```
+//!   # #[cfg(feature = "blocking")] {
 //!   use entrez_rs::eutils::{Eutils, EFetch, DB};
 //!   use entrez_rs::parser::pubmed::{PubmedArticleSet};
 //! 
@@ -36,11 +42,30 @@
 //!                .expect("Parsing error");
 //! 
 //!   assert_eq!(parsed.articles.len(), 1);
+//!   # }
 //!   ```
 
 
+use std::io::{BufReader, Read};
+use std::path::Path;
 use roxmltree::{Node, Document};
-use super::super::errors::{Error};
+use super::super::errors::{Error, ParsingErrorType, TextPosition};
+use super::super::instrument::ParseSpan;
+use super::baseline;
+
+mod citation;
+mod date;
+mod markup;
+mod options;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod stream;
+
+pub use self::citation::{parse_citation, ParsedCitation};
+pub use self::date::{parse_medline_date, parse_month, DatePrecision, DateRange, PartialDate, Season};
+pub use self::markup::{Inline, Markup};
+pub use self::options::{Diagnostic, ParseContext, ParseMode, ParseOptions};
+pub use self::stream::PubmedArticleReader;
 
 
 #[derive(Debug, PartialEq)]
@@ -60,17 +85,35 @@ pub struct ArticleIdList {
     pub article_ids: Vec<ArticleId>
 }
 
+/// A `<ReferenceList>`, optionally titled, which may contain nested lists
+/// (e.g. supplementary references).
 #[derive(Debug, PartialEq)]
 pub struct ReferenceList {
-    pub references: Vec<Reference>
+    pub title: Option<String>,
+    pub references: Vec<Reference>,
+    pub reference_lists: Vec<ReferenceList>
 }
 
 #[derive(Debug, PartialEq)]
 pub struct PubmedData {
     pub publication_status: Option<String>,
     pub article_id_list: Option<ArticleIdList>,
-    pub reference_list: Option<ReferenceList>,
-    pub history: Vec<PubMedPubDate>
+    pub reference_lists: Vec<ReferenceList>,
+    pub history: Vec<PubMedPubDate>,
+    /// The `ObjectList` element, linking the record to other resources.
+    pub object_list: Vec<Object>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Object {
+    pub object_type: Option<String>,
+    pub params: Vec<Param>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Param {
+    pub name: Option<String>,
+    pub value: Option<String>
 }
 
 #[derive(Debug, PartialEq)]
@@ -78,7 +121,10 @@ pub struct PubMedPubDate {
     pub pub_status: Option<String>,
     pub year: Option<String>,
     pub month: Option<String>,
-    pub day: Option<String>
+    pub day: Option<String>,
+    pub hour: Option<String>,
+    pub minute: Option<String>,
+    pub second: Option<String>
 }
 
 // MedlineCitation Fields
@@ -98,7 +144,11 @@ pub struct ISSN {
 pub struct PubDate {
     pub year: Option<String>,
     pub month: Option<String>,
-    pub day: Option<String>
+    pub day: Option<String>,
+    pub season: Option<String>,
+    /// The free-form date used instead of the other fields by many older records,
+    /// e.g. `1998 Dec-1999 Jan`. See [range](PubDate::range) for a typed version.
+    pub medline_date: Option<String>
 }
 
 #[derive(Debug, PartialEq)]
@@ -124,26 +174,134 @@ pub struct ELocationID {
     pub value: Option<String>
 }
 
+#[derive(Debug, PartialEq)]
+pub struct Pagination {
+    pub start_page: Option<String>,
+    pub end_page: Option<String>,
+    pub medline_pgn: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ArticleDate {
+    pub date_type: Option<String>,
+    pub year: Option<String>,
+    pub month: Option<String>,
+    pub day: Option<String>
+}
+
 #[derive(Debug, PartialEq)]
 pub struct AbstractText {
     pub label: Option<String>,
     pub nlm_category: Option<String>,
-    pub value: Option<String>
+    /// The text without formatting. `markup` renders it to HTML or Markdown.
+    pub value: Option<String>,
+    pub markup: Markup
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Abstract {
-    pub text: Vec<AbstractText>
+    pub text: Vec<AbstractText>,
+    pub copyright_information: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OtherAbstract {
+    pub abstract_type: Option<String>,
+    pub language: Option<String>,
+    pub text: Vec<AbstractText>,
+    pub copyright_information: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Identifier {
+    pub source: Option<String>,
+    pub value: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AffiliationInfo {
+    pub affiliation: Option<String>,
+    pub identifiers: Vec<Identifier>
+}
+
+/// A person or collective. Besides `Author`, it is used for the
+/// `Investigator` and `PersonalNameSubject` elements, which share its layout.
+#[derive(Debug, PartialEq)]
+pub struct Author {
+    pub valid_yn: Option<String>,
+    pub equal_contrib: Option<String>,
+    pub last_name: Option<String>,
+    pub fore_name: Option<String>,
+    pub initials: Option<String>,
+    pub suffix: Option<String>,
+    pub collective_name: Option<String>,
+    pub identifiers: Vec<Identifier>,
+    pub affiliation_info: Vec<AffiliationInfo>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AuthorList {
+    pub complete_yn: Option<String>,
+    /// `authors` or `editors`, only set on book records.
+    pub list_type: Option<String>,
+    pub authors: Vec<Author>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PublicationType {
+    pub ui: Option<String>,
+    pub value: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Grant {
+    pub grant_id: Option<String>,
+    pub acronym: Option<String>,
+    pub agency: Option<String>,
+    pub country: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GrantList {
+    pub complete_yn: Option<String>,
+    pub grants: Vec<Grant>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DataBank {
+    pub data_bank_name: Option<String>,
+    pub accession_numbers: Vec<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DataBankList {
+    pub complete_yn: Option<String>,
+    pub data_banks: Vec<DataBank>
 }
 
+// DataBankName values NLM uses for clinical trial registries.
+const TRIAL_REGISTRIES: [&str; 17] = [
+    "ANZCTR", "ChiCTR", "ClinicalTrials.gov", "CRiS", "CTRI", "DRKS", "EudraCT", "IRCT", "ISRCTN",
+    "JPRN", "NTR", "PACTR", "ReBec", "REPEC", "RPCEC", "SLCTR", "TCTR"
+];
+
 #[derive(Debug, PartialEq)]
 pub struct Article {
     pub pub_model: Option<String>,
+    /// The `ArticleTitle` as plain text; its formatting is kept in `title_markup`.
     pub title: Option<String>,
+    pub title_markup: Option<Markup>,
     pub journal: Option<Journal>,
     pub elocation_id: Option<ELocationID>,
     pub language: Option<String>,
-    pub abstract_text: Option<Abstract>
+    pub abstract_text: Option<Abstract>,
+    pub author_list: Option<AuthorList>,
+    pub grant_list: Option<GrantList>,
+    pub publication_type_list: Vec<PublicationType>,
+    pub data_bank_list: Option<DataBankList>,
+    pub pagination: Option<Pagination>,
+    pub vernacular_title: Option<String>,
+    pub article_dates: Vec<ArticleDate>
 }
 
 #[derive(Debug, PartialEq)]
@@ -154,14 +312,180 @@ pub struct MedlineJournalInfo {
     pub issn_linking: Option<String>
 }
 
+#[derive(Debug, PartialEq)]
+pub struct DescriptorName {
+    pub ui: Option<String>,
+    pub major_topic_yn: Option<String>,
+    pub descriptor_type: Option<String>,
+    pub value: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct QualifierName {
+    pub ui: Option<String>,
+    pub major_topic_yn: Option<String>,
+    pub value: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MeshHeading {
+    pub descriptor_name: Option<DescriptorName>,
+    pub qualifier_names: Vec<QualifierName>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MeshHeadingList {
+    pub mesh_headings: Vec<MeshHeading>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NameOfSubstance {
+    pub ui: Option<String>,
+    pub value: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Chemical {
+    pub registry_number: Option<String>,
+    pub name_of_substance: Option<NameOfSubstance>
+}
+
+/// The kind of identifier found in [Chemical::registry_number].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RegistryNumberType {
+    /// Chemical Abstracts Service registry number, e.g. `50-99-7`.
+    CAS,
+    /// FDA Unique Ingredient Identifier, e.g. `142M471B3J`.
+    UNII,
+    /// Enzyme Commission number, e.g. `EC 2.7.10.1`.
+    EC,
+    Other
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SupplMeshName {
+    pub suppl_type: Option<String>,
+    pub ui: Option<String>,
+    pub value: Option<String>
+}
+
+/// The relation between a citation and the one referenced in [CommentsCorrections].
+#[derive(Debug, PartialEq, Clone)]
+pub enum RefType {
+    AssociatedDataset,
+    AssociatedPublication,
+    CommentIn,
+    CommentOn,
+    CorrectedAndRepublishedIn,
+    CorrectedAndRepublishedFrom,
+    ErratumIn,
+    ErratumFor,
+    ExpressionOfConcernIn,
+    ExpressionOfConcernFor,
+    RepublishedIn,
+    RepublishedFrom,
+    RetractedAndRepublishedIn,
+    RetractedAndRepublishedFrom,
+    RetractionIn,
+    RetractionOf,
+    SummaryForPatientsIn,
+    OriginalReportIn,
+    ReprintIn,
+    ReprintOf,
+    UpdateIn,
+    UpdateOf,
+    Cites,
+    Other(String)
+}
+
+impl From<&str> for RefType {
+    fn from(s: &str) -> Self {
+        match s {
+            "AssociatedDataset" => RefType::AssociatedDataset,
+            "AssociatedPublication" => RefType::AssociatedPublication,
+            "CommentIn" => RefType::CommentIn,
+            "CommentOn" => RefType::CommentOn,
+            "CorrectedandRepublishedIn" => RefType::CorrectedAndRepublishedIn,
+            "CorrectedandRepublishedFrom" => RefType::CorrectedAndRepublishedFrom,
+            "ErratumIn" => RefType::ErratumIn,
+            "ErratumFor" => RefType::ErratumFor,
+            "ExpressionOfConcernIn" => RefType::ExpressionOfConcernIn,
+            "ExpressionOfConcernFor" => RefType::ExpressionOfConcernFor,
+            "RepublishedIn" => RefType::RepublishedIn,
+            "RepublishedFrom" => RefType::RepublishedFrom,
+            "RetractedandRepublishedIn" => RefType::RetractedAndRepublishedIn,
+            "RetractedandRepublishedFrom" => RefType::RetractedAndRepublishedFrom,
+            "RetractionIn" => RefType::RetractionIn,
+            "RetractionOf" => RefType::RetractionOf,
+            "SummaryForPatientsIn" => RefType::SummaryForPatientsIn,
+            "OriginalReportIn" => RefType::OriginalReportIn,
+            "ReprintIn" => RefType::ReprintIn,
+            "ReprintOf" => RefType::ReprintOf,
+            "UpdateIn" => RefType::UpdateIn,
+            "UpdateOf" => RefType::UpdateOf,
+            "Cites" => RefType::Cites,
+            other => RefType::Other(other.to_string())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CommentsCorrections {
+    pub ref_type: Option<RefType>,
+    pub ref_source: Option<String>,
+    pub pmid: Option<PMID>,
+    pub note: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Keyword {
+    pub major_topic_yn: Option<String>,
+    pub value: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct KeywordList {
+    pub owner: Option<String>,
+    pub keywords: Vec<Keyword>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GeneralNote {
+    pub owner: Option<String>,
+    pub value: Option<String>
+}
+
+/// An identifier of the citation assigned by another organization, e.g. `NASA`.
+#[derive(Debug, PartialEq)]
+pub struct OtherID {
+    pub source: Option<String>,
+    pub value: Option<String>
+}
+
 #[derive(Debug, PartialEq)]
 pub struct MedlineCitation {
     pub status: Option<String>,
     pub owner: Option<String>,
     pub pmid: Option<PMID>,
+    pub date_completed: Option<PubDate>,
     pub date_revised: Option<PubDate>,
     pub article: Option<Article>,
-    pub medline_journal_info: Option<MedlineJournalInfo>
+    pub medline_journal_info: Option<MedlineJournalInfo>,
+    pub mesh_heading_list: Option<MeshHeadingList>,
+    pub keyword_lists: Vec<KeywordList>,
+    pub chemical_list: Vec<Chemical>,
+    pub suppl_mesh_list: Vec<SupplMeshName>,
+    pub gene_symbol_list: Vec<String>,
+    pub comments_corrections_list: Vec<CommentsCorrections>,
+    pub other_abstracts: Vec<OtherAbstract>,
+    pub coi_statement: Option<String>,
+    pub investigator_list: Vec<Author>,
+    pub personal_name_subject_list: Vec<Author>,
+    pub general_notes: Vec<GeneralNote>,
+    pub space_flight_missions: Vec<String>,
+    pub citation_subsets: Vec<String>,
+    pub number_of_references: Option<String>,
+    pub other_ids: Vec<OtherID>
 }
 
 #[derive(Debug, PartialEq)]
@@ -170,30 +494,204 @@ pub struct PubmedArticle {
     pub pubmed_data: Option<PubmedData>
 }
 
+// PubmedBookArticle fields
+#[derive(Debug, PartialEq)]
+pub struct Publisher {
+    pub publisher_name: Option<String>,
+    pub publisher_location: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LocationLabel {
+    pub label_type: Option<String>,
+    pub value: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Section {
+    pub location_label: Option<LocationLabel>,
+    pub section_title: Option<String>,
+    pub sections: Vec<Section>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ItemList {
+    pub list_type: Option<String>,
+    pub items: Vec<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Book {
+    pub publisher: Option<Publisher>,
+    pub book_title: Option<String>,
+    pub pub_date: Option<PubDate>,
+    /// First and last years of publication of a series, e.g. a database updated yearly.
+    pub beginning_date: Option<PubDate>,
+    pub ending_date: Option<PubDate>,
+    pub author_lists: Vec<AuthorList>,
+    pub investigator_list: Vec<Author>,
+    pub volume: Option<String>,
+    pub volume_titles: Vec<String>,
+    pub edition: Option<String>,
+    pub collection_title: Option<String>,
+    pub isbns: Vec<String>,
+    pub elocation_ids: Vec<ELocationID>,
+    pub medium: Option<String>,
+    pub report_number: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BookDocument {
+    pub pmid: Option<PMID>,
+    pub article_id_list: Option<ArticleIdList>,
+    pub book: Option<Book>,
+    pub location_labels: Vec<LocationLabel>,
+    pub article_title: Option<String>,
+    pub article_title_markup: Option<Markup>,
+    pub vernacular_title: Option<String>,
+    pub pagination: Option<Pagination>,
+    pub languages: Vec<String>,
+    pub author_lists: Vec<AuthorList>,
+    pub investigator_list: Vec<Author>,
+    pub publication_type_list: Vec<PublicationType>,
+    pub abstract_text: Option<Abstract>,
+    pub sections: Vec<Section>,
+    pub keyword_lists: Vec<KeywordList>,
+    pub contribution_date: Option<PubDate>,
+    pub date_revised: Option<PubDate>,
+    /// How to cite the document, as given by the publisher.
+    pub citation_string: Option<String>,
+    pub grant_list: Option<GrantList>,
+    pub item_lists: Vec<ItemList>,
+    pub reference_lists: Vec<ReferenceList>
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PubmedBookArticle {
+    pub book_document: Option<BookDocument>,
+    /// The `PubmedBookData` element, which shares the layout of `PubmedData`.
+    pub pubmed_book_data: Option<PubmedData>
+}
+
+/// A record of a [PubmedArticleSet], either a journal article or a Bookshelf book/chapter.
+/// Both are boxed to keep the records of a set small to move around.
+#[derive(Debug, PartialEq)]
+pub enum PubmedRecord {
+    Article(Box<PubmedArticle>),
+    BookArticle(Box<PubmedBookArticle>)
+}
+
 #[derive(Debug, PartialEq)]
 pub struct PubmedArticleSet {
-    pub articles: Vec<PubmedArticle>
+    pub articles: Vec<PubmedRecord>,
+    /// PMIDs listed in `<DeleteCitation>`, found in the daily update files.
+    /// Records with these PMIDs (and versions) should be removed from a local corpus.
+    pub deleted_citations: Vec<PMID>
 }
 
 pub trait FromXMLNode {
-    fn from_node(node: &Node) -> Self;
+    /// Parses `node`, skipping the elements the parser does not know.
+    fn from_node(node: &Node) -> Self
+    where Self: Sized {
+        Self::from_node_with(node, &mut ParseContext::default())
+    }
+
+    /// Parses `node`, reporting the elements the parser does not know to `ctx`.
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self
+    where Self: Sized;
+}
+
+// The element children of a list element that are named `name`.
+// Any other element is reported as unknown.
+fn list_items<'a, 'input>(node: &Node<'a, 'input>, name: &str, ctx: &mut ParseContext) -> Vec<Node<'a, 'input>> {
+    node.children()
+        .filter(|n| n.is_element())
+        .filter(|n| {
+            let known = n.has_tag_name(name);
+            if !known {
+                ctx.unknown(n);
+            }
+            known
+        })
+        .collect()
+}
+
+fn position(document: &Document, node: &Node) -> TextPosition {
+    let byte = node.range().start;
+    let pos = document.text_pos_at(byte);
+
+    TextPosition {
+        byte,
+        line: pos.row,
+        column: pos.col
+    }
+}
+
+// Like `Document::parse`, but reports empty documents as `MissingRoot`.
+fn parse_document(xml: &str) -> Result<Document<'_>, Error> {
+    if xml.trim().is_empty() {
+        return Err(Error::ParsingError(ParsingErrorType::MissingRoot));
+    }
+
+    Document::parse(xml).map_err(|e| match e {
+        roxmltree::Error::NoRootNode => Error::ParsingError(ParsingErrorType::MissingRoot),
+        e => e.into()
+    })
+}
+
+// Returns the root element if it is `expected`. Otherwise reports the `<ERROR>`
+// NCBI sends instead of results, either as the root or inside it (`<eFetchResult>`),
+// or the unexpected root element.
+fn check_root<'a, 'input>(document: &'a Document<'input>, expected: &'static str) -> Result<Node<'a, 'input>, Error> {
+    let root = document.root_element();
+    if root.has_tag_name(expected) {
+        return Ok(root);
+    }
+
+    let error = match root.descendants().find(|n| n.has_tag_name("ERROR")) {
+        Some(node) => ParsingErrorType::NCBIError {
+            message: text_content(&node).unwrap_or_default().trim().to_string(),
+            position: position(document, &node)
+        },
+        None => ParsingErrorType::UnexpectedRoot {
+            expected,
+            found: root.tag_name().name().to_string(),
+            position: position(document, &root)
+        }
+    };
+
+    Err(Error::ParsingError(error))
+}
+
+/// All the text inside `node`, including the text of inline elements such as `<i>`
+/// or `<sup>`, which `Node::text` stops at. `None` if the element has no text.
+fn text_content(node: &Node) -> Option<String> {
+    let text: String = node
+        .descendants()
+        .filter(|d| d.is_text())
+        .filter_map(|d| d.text())
+        .collect();
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
 }
 
 //PubmedData fields
 impl FromXMLNode for ArticleIdList {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut article_id_list = ArticleIdList {
             article_ids: Vec::new()
         };
         
-        for elem in node
-                         .children()
-                         .filter(|n| n.is_element()) {
+        for elem in list_items(node, "ArticleId", ctx) {
 
             article_id_list
             .article_ids
             .push(
-                ArticleId::from_node(&elem)
+                ArticleId::from_node_with(&elem, ctx)
             )                    
 
                         
@@ -203,16 +701,16 @@ impl FromXMLNode for ArticleIdList {
     }
 }
 impl FromXMLNode for ArticleId {
-    fn from_node(node:&Node) -> Self {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
         ArticleId {
             id_type: node.attribute("IdType").map(|n| n.to_string()),
-            id: node.text().map(|n| n.to_string())
+            id: text_content(node)
                      
        }
     } 
 }
 impl FromXMLNode for Reference {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut reference = Reference {
             citation: None,
             article_id_list: ArticleIdList {
@@ -222,61 +720,94 @@ impl FromXMLNode for Reference {
 
         for elem in node.children().filter(|x| x.is_element()) {
             match elem.tag_name().name() {
-                "Citation" => reference.citation = elem.text().map(|x| x.to_string()),
-                "ArticleIdList"  => reference.article_id_list = ArticleIdList::from_node(&elem),
-                _          => ()
+                "Citation" => reference.citation = text_content(&elem),
+                "ArticleIdList"  => reference.article_id_list = ArticleIdList::from_node_with(&elem, ctx),
+                _          => ctx.unknown(&elem)
             }
         }
         reference
     }
 }
 impl FromXMLNode for ReferenceList {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut referene_list = ReferenceList {
-            references: Vec::new()
+            title: None,
+            references: Vec::new(),
+            reference_lists: Vec::new()
         };
 
         for elem in node
                         .children()
                         .filter(|n| n.is_element()) {
-                            referene_list.references.push(
-                                Reference::from_node(&elem)
-                            )
-                        }
+            match elem.tag_name().name() {
+                "Title"         => referene_list.title = text_content(&elem),
+                "Reference"     => referene_list.references.push(Reference::from_node_with(&elem, ctx)),
+                "ReferenceList" => referene_list.reference_lists.push(ReferenceList::from_node_with(&elem, ctx)),
+                _               => ctx.unknown(&elem)
+            }
+        }
         
         referene_list
     }
 }
 impl FromXMLNode for PubMedPubDate {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut pubmed_pub_date = PubMedPubDate {
             pub_status: node.attribute("PubStatus")
                              .map(|s| s.to_string()),
             year: None,
             month: None,
-            day: None
+            day: None,
+            hour: None,
+            minute: None,
+            second: None
         };
 
         for elem in node
             .children()
             .filter(|e| e.is_element()){
              match elem.tag_name().name() {
-                 "Year"  => pubmed_pub_date.year = elem.text().map(|e| e.to_string()),
-                 "Month" => pubmed_pub_date.month = elem.text().map(|e| e.to_string()),  
-                 "Day"   => pubmed_pub_date.day = elem.text().map(|e| e.to_string()),  
-                 _       => ()              
+                 "Year"  => pubmed_pub_date.year = text_content(&elem),
+                 "Month" => pubmed_pub_date.month = text_content(&elem),  
+                 "Day"   => pubmed_pub_date.day = text_content(&elem),  
+                 "Hour"  => pubmed_pub_date.hour = text_content(&elem),
+                 "Minute" => pubmed_pub_date.minute = text_content(&elem),
+                 "Second" => pubmed_pub_date.second = text_content(&elem),
+                 _       => ctx.unknown(&elem)              
              }
         }
         pubmed_pub_date
     }
 }
+impl FromXMLNode for Param {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        Param {
+            name: node.attribute("Name")
+                  .map(|s| s.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for Object {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        Object {
+            object_type: node.attribute("Type")
+                         .map(|s| s.to_string()),
+            params: list_items(node, "Param", ctx)
+                .iter()
+                .map(|param| Param::from_node_with(param, ctx))
+                .collect()
+        }
+    }
+}
 impl FromXMLNode for PubmedData {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut pubmed_data = PubmedData {
             publication_status: None,
             article_id_list: None,
-            reference_list: None,
-            history: Vec::new()
+            reference_lists: Vec::new(),
+            history: Vec::new(),
+            object_list: Vec::new()
         };
         
         for elem in node
@@ -284,17 +815,22 @@ impl FromXMLNode for PubmedData {
             .filter(|n| n.is_element()) {
             
             match elem.tag_name().name() {
-                "PublicationStatus" => pubmed_data.publication_status = elem.text().map(|e| e.to_string()),
-                "ArticleIdList"     => pubmed_data.article_id_list = Some(ArticleIdList::from_node(&elem)),
-                "ReferenceList"     => pubmed_data.reference_list  = Some(ReferenceList::from_node(&elem)),
+                "PublicationStatus" => pubmed_data.publication_status = text_content(&elem),
+                "ArticleIdList"     => pubmed_data.article_id_list = Some(ArticleIdList::from_node_with(&elem, ctx)),
+                "ReferenceList"     => pubmed_data.reference_lists.push(ReferenceList::from_node_with(&elem, ctx)),
                 "History"           => {
-                    for pub_date in elem.children().filter(|e| e.is_element()) {
+                    for pub_date in list_items(&elem, "PubMedPubDate", ctx) {
                         pubmed_data.history.push(
-                            PubMedPubDate::from_node(&pub_date)
+                            PubMedPubDate::from_node_with(&pub_date, ctx)
                         )
                     }
                 }
-                _                   => ()
+                "ObjectList"        => {
+                    for object in list_items(&elem, "Object", ctx) {
+                        pubmed_data.object_list.push(Object::from_node_with(&object, ctx))
+                    }
+                }
+                _                   => ctx.unknown(&elem)
 
             }
 
@@ -306,21 +842,23 @@ impl FromXMLNode for PubmedData {
 
 //MedlineCitation fields
 impl FromXMLNode for PMID {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
         PMID {
             version: node.attribute("Version")
                              .map(|s| s.to_string()),
             
-            value: node.text().map(|s| s.to_string())
+            value: text_content(node)
         }
     }
 }
 impl FromXMLNode for PubDate {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
             let mut date = PubDate {
                 year: None,
                 month: None,
-                day: None
+                day: None,
+                season: None,
+                medline_date: None
             };
 
             for elem in node
@@ -328,10 +866,12 @@ impl FromXMLNode for PubDate {
                 .filter(|e| e.is_element()) {
                 
                 match elem.tag_name().name() {
-                    "Year" => date.year = elem.text().map(|s| s.to_string()),
-                    "Month" => date.month = elem.text().map(|s| s.to_string()),
-                    "Day" => date.day = elem.text().map(|s| s.to_string()),
-                    _ => ()
+                    "Year" => date.year = text_content(&elem),
+                    "Month" => date.month = text_content(&elem),
+                    "Day" => date.day = text_content(&elem),
+                    "Season" => date.season = text_content(&elem),
+                    "MedlineDate" => date.medline_date = text_content(&elem),
+                    _ => ctx.unknown(&elem)
                 }
             }
            
@@ -340,17 +880,16 @@ impl FromXMLNode for PubDate {
     }
 }
 impl FromXMLNode for ISSN {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
         ISSN {
             issn_type: node.attribute("IssnType")
                        .map(|n| n.to_string()),
-            value: node.text()
-                   .map(|t| t.to_string())
+            value: text_content(node)
         }
     }
 }
 impl FromXMLNode for JournalIssue {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut journal_issue =  JournalIssue {
             cited_medium: node.attribute("CitedMedium")
                           .map(|e| e.to_string()),
@@ -364,22 +903,22 @@ impl FromXMLNode for JournalIssue {
             
             match elem.tag_name().name() {
                 "Volume" => {
-                    journal_issue.volume = elem.text().map(|e| e.to_string())
+                    journal_issue.volume = text_content(&elem)
                 },
                 "Issue" => {
-                    journal_issue.issue = elem.text().map(|e| e.to_string()) 
+                    journal_issue.issue = text_content(&elem) 
                 },
                 "PubDate" => {
-                    journal_issue.pub_date = Some(PubDate::from_node(&elem))
+                    journal_issue.pub_date = Some(PubDate::from_node_with(&elem, ctx))
                 }
-                _ => ()
+                _ => ctx.unknown(&elem)
             }
         }
         journal_issue
     }
 }
 impl FromXMLNode for Journal {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut journal = Journal {
             issn: None,
             journal_issue: None,
@@ -392,20 +931,18 @@ impl FromXMLNode for Journal {
                  .filter(|e| e.is_element()) { 
             match elem.tag_name().name() {
                 "ISSN" => {
-                    journal.issn = Some(ISSN::from_node(&elem))
+                    journal.issn = Some(ISSN::from_node_with(&elem, ctx))
                 },
                 "JournalIssue" => {
-                    journal.journal_issue = Some(JournalIssue::from_node(&elem))
+                    journal.journal_issue = Some(JournalIssue::from_node_with(&elem, ctx))
                 },
                 "Title" => {
-                    journal.title = elem.text()
-                                    .map(|e| e.to_string())
+                    journal.title = text_content(&elem)
                 },
                 "ISOAbbreviation" => {
-                    journal.iso_abbr = elem.text()
-                                       .map(|e| e.to_string())
+                    journal.iso_abbr = text_content(&elem)
                 },
-                _ => ()
+                _ => ctx.unknown(&elem)
             }
         }
 
@@ -413,131 +950,81 @@ impl FromXMLNode for Journal {
     }
 }
 impl FromXMLNode for ELocationID {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
         ELocationID {
             eid_type: node.attribute("EIdType")
                       .map(|e| e.to_string()),
             valid_yn: node.attribute("ValidYN")
                       .map(|e| e.to_string()),
-            value: node.text().map(|t|t.to_string())
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for Pagination {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut pagination = Pagination {
+            start_page: None,
+            end_page: None,
+            medline_pgn: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "StartPage" => pagination.start_page = text_content(&elem),
+                    "EndPage" => pagination.end_page = text_content(&elem),
+                    "MedlinePgn" => pagination.medline_pgn = text_content(&elem),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        pagination
+    }
+}
+impl FromXMLNode for ArticleDate {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut article_date = ArticleDate {
+            date_type: node.attribute("DateType")
+                       .map(|e| e.to_string()),
+            year: None,
+            month: None,
+            day: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "Year" => article_date.year = text_content(&elem),
+                    "Month" => article_date.month = text_content(&elem),
+                    "Day" => article_date.day = text_content(&elem),
+                    _ => ctx.unknown(&elem)
+                }
         }
+
+        article_date
     }
 }
 impl FromXMLNode for AbstractText {
-    fn from_node(node: &Node) -> Self {
-        let mut abstract_text = AbstractText {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let markup = Markup::from_node_with(node, ctx);
+
+        AbstractText {
             label: node.attribute("Label")
                    .map(|e| e.to_string()),
             nlm_category: node.attribute("NlmCategory")
                    .map(|e| e.to_string()),
-            value: None
-        };
-        
-        let mut txt = Vec::new();
-        for elem in node 
-            .children() {
-            
-            if elem.is_text() {
-                match elem.text() {
-                    Some(t) => txt.push(t),
-                    None => ()
-                }
-                
-            }
-            
-            if elem.is_element() {
-               &txt.push("<");
-               if elem.namespaces().len() > 0 {
-                   for ns in elem.namespaces() {
-                       txt.push(ns.name().unwrap());
-                       txt.push(":");
-                       txt.push(elem.tag_name().name());
-                       txt.push(" xmlns:");
-                       txt.push(ns.name().unwrap());
-                       txt.push("=");
-                       txt.push("\"");
-                       txt.push(ns.uri());
-                       txt.push("\"");
-                   }
-               }
-               else {
-                   &txt.push(elem.tag_name().name());
-               }
-               &txt.push(">");
-                
-                if elem.has_children() {
-                        for c in elem.children() {
-                           
-                            if c.is_element() {
-                           &txt.push("<");
-                           if c.namespaces().len() > 0 {
-                           for ns in c.namespaces() {
-                           txt.push(ns.name().unwrap());
-                           txt.push(":");
-                           txt.push(c.tag_name().name());
-                           txt.push(" xmlns:");
-                           txt.push(ns.name().unwrap());
-                           txt.push("=");
-                           txt.push("\"");
-                           txt.push(ns.uri());
-                           txt.push("\"");
-                        }
-                   }
-                   else {
-                       &txt.push(c.tag_name().name());
-                   }
-                   &txt.push(">");
-                   match c.text() {
-                                    Some(t) => txt.push(t),
-                                    None => ()
-                               }
-                   &txt.push("</");
-                      if c.namespaces().len() > 0 {
-                          for n in c.namespaces() {
-                                  txt.push(n.name().unwrap());
-                                  txt.push(":");
-                          }
-               }
-               &txt.push(c.tag_name().name());
-               &txt.push(">");
-                   }
-                   else {
-                    match c.text() {
-                        Some(t) => &txt.push(&t),
-                        None => &txt.push("")
-                    };
-                }
-                        
-                    
-                    };
-                }// ends here
-                else {
-                    match elem.text() {
-                        Some(t) => &txt.push(&t),
-                        None => &txt.push("")
-                    };
-                }
-                
-               &txt.push("</");
-               if elem.namespaces().len() > 0 {
-                   for ns in elem.namespaces() {
-                           txt.push(ns.name().unwrap());
-                           txt.push(":");
-                   }
-               }
-               &txt.push(elem.tag_name().name());
-               &txt.push(">");
-            }
-            abstract_text.value = Some(txt.concat())
+            value: if markup.is_empty() { None } else { Some(markup.to_plain_text()) },
+            markup
         }
-        
-        abstract_text
     }
 }
 impl FromXMLNode for Abstract {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut abs = Abstract {
-            text: Vec::new()
+            text: Vec::new(),
+            copyright_information: None
         };
 
         for elem in node 
@@ -545,9 +1032,10 @@ impl FromXMLNode for Abstract {
             .filter(|e| e.is_element()) {
                 match elem.tag_name().name() {
                     "AbstractText" => abs.text.push(
-                        AbstractText::from_node(&elem)
+                        AbstractText::from_node_with(&elem, ctx)
                     ),
-                    _ => ()
+                    "CopyrightInformation" => abs.copyright_information = text_content(&elem),
+                    _ => ctx.unknown(&elem)
                 }
         }
 
@@ -555,51 +1043,294 @@ impl FromXMLNode for Abstract {
         
     }
 }
-impl FromXMLNode for Article {
-    fn from_node(node: &Node) -> Self {
-        let mut article = Article {
-            pub_model: node.attribute("PubModel")
-                            .map(|a| a.to_string()),
-            title: None,
-            journal: None,
-            elocation_id: None,
-            language: None,
-            abstract_text: None
-            
-      };
-      
-      for elem in node 
-                       .children()
-                       .filter(|e| e.is_element()) {
-          
-          match elem.tag_name().name() {
-              "ArticleTitle" => {
-                  article.title = elem.text() 
-                                  .map(|e| e.to_string())
-              },
-              "Journal" => {
-                  article.journal = Some(Journal::from_node(&elem))
-              },
-              "ELocationID" => {
-                  article.elocation_id = Some(ELocationID::from_node(&elem))
-              },
-              "Language" => {
-                  article.language = elem.text()
-                                         .map(|e| e.to_string())
-              },
-              "Abstract" => {
-                  article.abstract_text = Some(Abstract::from_node(&elem))
-              },
-              _ => ()
-          }
-      }
-      article
-    }
-}
-impl FromXMLNode for MedlineJournalInfo {
-    fn from_node(node: &Node) -> Self {
-        let mut med_journ_info = MedlineJournalInfo {
-            country: None,
+impl FromXMLNode for OtherAbstract {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut other_abstract = OtherAbstract {
+            abstract_type: node.attribute("Type")
+                           .map(|e| e.to_string()),
+            language: node.attribute("Language")
+                      .map(|e| e.to_string()),
+            text: Vec::new(),
+            copyright_information: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "AbstractText" => other_abstract.text.push(
+                        AbstractText::from_node_with(&elem, ctx)
+                    ),
+                    "CopyrightInformation" => other_abstract.copyright_information = text_content(&elem),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        other_abstract
+    }
+}
+impl FromXMLNode for Identifier {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        Identifier {
+            source: node.attribute("Source")
+                    .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for AffiliationInfo {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut affiliation_info = AffiliationInfo {
+            affiliation: None,
+            identifiers: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "Affiliation" => affiliation_info.affiliation = text_content(&elem),
+                    "Identifier" => affiliation_info.identifiers.push(Identifier::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        affiliation_info
+    }
+}
+impl FromXMLNode for Author {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut author = Author {
+            valid_yn: node.attribute("ValidYN")
+                      .map(|e| e.to_string()),
+            equal_contrib: node.attribute("EqualContrib")
+                      .map(|e| e.to_string()),
+            last_name: None,
+            fore_name: None,
+            initials: None,
+            suffix: None,
+            collective_name: None,
+            identifiers: Vec::new(),
+            affiliation_info: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "LastName" => author.last_name = text_content(&elem),
+                    "ForeName" => author.fore_name = text_content(&elem),
+                    "Initials" => author.initials = text_content(&elem),
+                    "Suffix" => author.suffix = text_content(&elem),
+                    "CollectiveName" => author.collective_name = text_content(&elem),
+                    "Identifier" => author.identifiers.push(Identifier::from_node_with(&elem, ctx)),
+                    "AffiliationInfo" => author.affiliation_info.push(AffiliationInfo::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        author
+    }
+}
+impl FromXMLNode for AuthorList {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut author_list = AuthorList {
+            complete_yn: node.attribute("CompleteYN")
+                         .map(|e| e.to_string()),
+            list_type: node.attribute("Type")
+                       .map(|e| e.to_string()),
+            authors: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "Author" => author_list.authors.push(Author::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        author_list
+    }
+}
+impl FromXMLNode for PublicationType {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        PublicationType {
+            ui: node.attribute("UI")
+                .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for Grant {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut grant = Grant {
+            grant_id: None,
+            acronym: None,
+            agency: None,
+            country: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "GrantID" => grant.grant_id = text_content(&elem),
+                    "Acronym" => grant.acronym = text_content(&elem),
+                    "Agency" => grant.agency = text_content(&elem),
+                    "Country" => grant.country = text_content(&elem),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        grant
+    }
+}
+impl FromXMLNode for GrantList {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut grant_list = GrantList {
+            complete_yn: node.attribute("CompleteYN")
+                         .map(|e| e.to_string()),
+            grants: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "Grant" => grant_list.grants.push(Grant::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        grant_list
+    }
+}
+impl FromXMLNode for DataBank {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut data_bank = DataBank {
+            data_bank_name: None,
+            accession_numbers: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "DataBankName" => data_bank.data_bank_name = text_content(&elem),
+                    "AccessionNumberList" => {
+                        for number in list_items(&elem, "AccessionNumber", ctx) {
+                            if let Some(t) = text_content(&number) {
+                                data_bank.accession_numbers.push(t)
+                            }
+                        }
+                    },
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        data_bank
+    }
+}
+impl FromXMLNode for DataBankList {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut data_bank_list = DataBankList {
+            complete_yn: node.attribute("CompleteYN")
+                         .map(|e| e.to_string()),
+            data_banks: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "DataBank" => data_bank_list.data_banks.push(DataBank::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        data_bank_list
+    }
+}
+impl FromXMLNode for Article {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut article = Article {
+            pub_model: node.attribute("PubModel")
+                            .map(|a| a.to_string()),
+            title: None,
+            title_markup: None,
+            journal: None,
+            elocation_id: None,
+            language: None,
+            abstract_text: None,
+            author_list: None,
+            grant_list: None,
+            publication_type_list: Vec::new(),
+            data_bank_list: None,
+            pagination: None,
+            vernacular_title: None,
+            article_dates: Vec::new()
+            
+      };
+      
+      for elem in node 
+                       .children()
+                       .filter(|e| e.is_element()) {
+          
+          match elem.tag_name().name() {
+              "ArticleTitle" => {
+                  let markup = Markup::from_node_with(&elem, ctx);
+                  article.title = Some(markup.to_plain_text());
+                  article.title_markup = Some(markup)
+              },
+              "Journal" => {
+                  article.journal = Some(Journal::from_node_with(&elem, ctx))
+              },
+              "ELocationID" => {
+                  article.elocation_id = Some(ELocationID::from_node_with(&elem, ctx))
+              },
+              "Language" => {
+                  article.language = text_content(&elem)
+              },
+              "Abstract" => {
+                  article.abstract_text = Some(Abstract::from_node_with(&elem, ctx))
+              },
+              "AuthorList" => {
+                  article.author_list = Some(AuthorList::from_node_with(&elem, ctx))
+              },
+              "GrantList" => {
+                  article.grant_list = Some(GrantList::from_node_with(&elem, ctx))
+              },
+              "DataBankList" => {
+                  article.data_bank_list = Some(DataBankList::from_node_with(&elem, ctx))
+              },
+              "Pagination" => {
+                  article.pagination = Some(Pagination::from_node_with(&elem, ctx))
+              },
+              "VernacularTitle" => {
+                  article.vernacular_title = text_content(&elem)
+              },
+              "ArticleDate" => {
+                  article.article_dates.push(ArticleDate::from_node_with(&elem, ctx))
+              },
+              "PublicationTypeList" => {
+                  for pub_type in list_items(&elem, "PublicationType", ctx) {
+                      article.publication_type_list.push(
+                          PublicationType::from_node_with(&pub_type, ctx)
+                      )
+                  }
+              },
+              _ => ctx.unknown(&elem)
+          }
+      }
+      article
+    }
+}
+impl FromXMLNode for MedlineJournalInfo {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut med_journ_info = MedlineJournalInfo {
+            country: None,
             medline_ta: None,
             nlm_unique_id: None,
             issn_linking: None
@@ -611,29 +1342,202 @@ impl FromXMLNode for MedlineJournalInfo {
                 match elem.tag_name().name() {
                     "Country" => {
                         med_journ_info
-                        .country = elem.text().map(|t| t.to_string())
+                        .country = text_content(&elem)
                     },
                     "MedlineTA" => {
                         med_journ_info
-                        .medline_ta = elem.text().map(|t| t.to_string())
+                        .medline_ta = text_content(&elem)
                     },
                     "NlmUniqueID" => {
                         med_journ_info
-                        .nlm_unique_id = elem.text().map(|t| t.to_string())
+                        .nlm_unique_id = text_content(&elem)
                     },
                     "ISSNLinking" => {
                         med_journ_info
-                        .issn_linking = elem.text().map(|t| t.to_string())
+                        .issn_linking = text_content(&elem)
                     },
-                    _ => ()
+                    _ => ctx.unknown(&elem)
 
                 }
             }
         med_journ_info
     }
 }
+impl FromXMLNode for DescriptorName {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        DescriptorName {
+            ui: node.attribute("UI")
+                .map(|e| e.to_string()),
+            major_topic_yn: node.attribute("MajorTopicYN")
+                .map(|e| e.to_string()),
+            descriptor_type: node.attribute("Type")
+                .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for QualifierName {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        QualifierName {
+            ui: node.attribute("UI")
+                .map(|e| e.to_string()),
+            major_topic_yn: node.attribute("MajorTopicYN")
+                .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for MeshHeading {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut mesh_heading = MeshHeading {
+            descriptor_name: None,
+            qualifier_names: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "DescriptorName" => mesh_heading.descriptor_name = Some(DescriptorName::from_node_with(&elem, ctx)),
+                    "QualifierName" => mesh_heading.qualifier_names.push(QualifierName::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        mesh_heading
+    }
+}
+impl FromXMLNode for MeshHeadingList {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut mesh_heading_list = MeshHeadingList {
+            mesh_headings: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "MeshHeading" => mesh_heading_list.mesh_headings.push(MeshHeading::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        mesh_heading_list
+    }
+}
+impl FromXMLNode for NameOfSubstance {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        NameOfSubstance {
+            ui: node.attribute("UI")
+                .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for Chemical {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut chemical = Chemical {
+            registry_number: None,
+            name_of_substance: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "RegistryNumber" => chemical.registry_number = text_content(&elem),
+                    "NameOfSubstance" => chemical.name_of_substance = Some(NameOfSubstance::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        chemical
+    }
+}
+impl FromXMLNode for SupplMeshName {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        SupplMeshName {
+            suppl_type: node.attribute("Type")
+                .map(|e| e.to_string()),
+            ui: node.attribute("UI")
+                .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for CommentsCorrections {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut comments_corrections = CommentsCorrections {
+            ref_type: node.attribute("RefType")
+                      .map(RefType::from),
+            ref_source: None,
+            pmid: None,
+            note: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "RefSource" => comments_corrections.ref_source = text_content(&elem),
+                    "PMID" => comments_corrections.pmid = Some(PMID::from_node_with(&elem, ctx)),
+                    "Note" => comments_corrections.note = text_content(&elem),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        comments_corrections
+    }
+}
+impl FromXMLNode for Keyword {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        Keyword {
+            major_topic_yn: node.attribute("MajorTopicYN")
+                .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for KeywordList {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut keyword_list = KeywordList {
+            owner: node.attribute("Owner")
+                   .map(|e| e.to_string()),
+            keywords: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "Keyword" => keyword_list.keywords.push(Keyword::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        keyword_list
+    }
+}
+impl FromXMLNode for GeneralNote {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        GeneralNote {
+            owner: node.attribute("Owner")
+                   .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for OtherID {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        OtherID {
+            source: node.attribute("Source")
+                    .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
 impl FromXMLNode for MedlineCitation {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut medline_citation = MedlineCitation {
             status: node.attribute("Status")
                              .map(|s| s.to_string()),
@@ -642,22 +1546,104 @@ impl FromXMLNode for MedlineCitation {
                              .map(|s| s.to_string()),
             pmid: None,
 
+            date_completed: None,
+
             date_revised: None,
 
             article: None,
 
-            medline_journal_info: None
+            medline_journal_info: None,
+
+            mesh_heading_list: None,
+
+            keyword_lists: Vec::new(),
+
+            chemical_list: Vec::new(),
+
+            suppl_mesh_list: Vec::new(),
+
+            gene_symbol_list: Vec::new(),
+
+            comments_corrections_list: Vec::new(),
+
+            other_abstracts: Vec::new(),
+
+            coi_statement: None,
+
+            investigator_list: Vec::new(),
+
+            personal_name_subject_list: Vec::new(),
+
+            general_notes: Vec::new(),
+
+            space_flight_missions: Vec::new(),
+
+            citation_subsets: Vec::new(),
+
+            number_of_references: None,
+
+            other_ids: Vec::new()
         };
 
         for elem in node
             .children() 
             .filter(|e| e.is_element()) {
                 match elem.tag_name().name() { 
-                    "PMID" => medline_citation.pmid =  Some(PMID::from_node(&elem)),
-                    "DateRevised" => medline_citation.date_revised = Some(PubDate::from_node(&elem)),
-                    "Article" => medline_citation.article = Some(Article::from_node(&elem)),  
-                    "MedlineJournalInfo" => medline_citation.medline_journal_info = Some(MedlineJournalInfo::from_node(&elem)),                                      
-                    _ => ()
+                    "PMID" => medline_citation.pmid =  Some(PMID::from_node_with(&elem, ctx)),
+                    "DateCompleted" => medline_citation.date_completed = Some(PubDate::from_node_with(&elem, ctx)),
+                    "DateRevised" => medline_citation.date_revised = Some(PubDate::from_node_with(&elem, ctx)),
+                    "Article" => medline_citation.article = Some(Article::from_node_with(&elem, ctx)),  
+                    "MedlineJournalInfo" => medline_citation.medline_journal_info = Some(MedlineJournalInfo::from_node_with(&elem, ctx)),                                      
+                    "MeshHeadingList" => medline_citation.mesh_heading_list = Some(MeshHeadingList::from_node_with(&elem, ctx)),
+                    "KeywordList" => medline_citation.keyword_lists.push(KeywordList::from_node_with(&elem, ctx)),
+                    "ChemicalList" => {
+                        for chemical in list_items(&elem, "Chemical", ctx) {
+                            medline_citation.chemical_list.push(Chemical::from_node_with(&chemical, ctx))
+                        }
+                    },
+                    "SupplMeshList" => {
+                        for suppl in list_items(&elem, "SupplMeshName", ctx) {
+                            medline_citation.suppl_mesh_list.push(SupplMeshName::from_node_with(&suppl, ctx))
+                        }
+                    },
+                    "CommentsCorrectionsList" => {
+                        for comment in list_items(&elem, "CommentsCorrections", ctx) {
+                            medline_citation.comments_corrections_list.push(CommentsCorrections::from_node_with(&comment, ctx))
+                        }
+                    },
+                    "OtherAbstract" => medline_citation.other_abstracts.push(OtherAbstract::from_node_with(&elem, ctx)),
+                    "CoiStatement" => medline_citation.coi_statement = text_content(&elem),
+                    "InvestigatorList" => {
+                        for investigator in list_items(&elem, "Investigator", ctx) {
+                            medline_citation.investigator_list.push(Author::from_node_with(&investigator, ctx))
+                        }
+                    },
+                    "PersonalNameSubjectList" => {
+                        for subject in list_items(&elem, "PersonalNameSubject", ctx) {
+                            medline_citation.personal_name_subject_list.push(Author::from_node_with(&subject, ctx))
+                        }
+                    },
+                    "GeneralNote" => medline_citation.general_notes.push(GeneralNote::from_node_with(&elem, ctx)),
+                    "SpaceFlightMission" => {
+                        if let Some(t) = text_content(&elem) {
+                            medline_citation.space_flight_missions.push(t)
+                        }
+                    },
+                    "CitationSubset" => {
+                        if let Some(t) = text_content(&elem) {
+                            medline_citation.citation_subsets.push(t)
+                        }
+                    },
+                    "GeneSymbolList" => {
+                        for symbol in list_items(&elem, "GeneSymbol", ctx) {
+                            if let Some(t) = text_content(&symbol) {
+                                medline_citation.gene_symbol_list.push(t)
+                            }
+                        }
+                    },
+                    "NumberOfReferences" => medline_citation.number_of_references = text_content(&elem),
+                    "OtherID" => medline_citation.other_ids.push(OtherID::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
                 }
         }
 
@@ -668,7 +1654,7 @@ impl FromXMLNode for MedlineCitation {
 
 //PubmedArticle
 impl FromXMLNode for PubmedArticle {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut pub_art = PubmedArticle {
             medline_citation: None,
             pubmed_data: None
@@ -678,9 +1664,9 @@ impl FromXMLNode for PubmedArticle {
             .children() 
             .filter(|e| e.is_element()) {
                 match elem.tag_name().name() { 
-                    "MedlineCitation" => pub_art.medline_citation =  Some(MedlineCitation::from_node(&elem)),
-                    "PubmedData" => pub_art.pubmed_data = Some(PubmedData::from_node(&elem)),
-                    _ => ()
+                    "MedlineCitation" => pub_art.medline_citation =  Some(MedlineCitation::from_node_with(&elem, ctx)),
+                    "PubmedData" => pub_art.pubmed_data = Some(PubmedData::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
                 }
         }
 
@@ -688,12 +1674,229 @@ impl FromXMLNode for PubmedArticle {
     }
 }
 
+//PubmedBookArticle
+impl FromXMLNode for Publisher {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut publisher = Publisher {
+            publisher_name: None,
+            publisher_location: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "PublisherName" => publisher.publisher_name = text_content(&elem),
+                    "PublisherLocation" => publisher.publisher_location = text_content(&elem),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        publisher
+    }
+}
+impl FromXMLNode for LocationLabel {
+    fn from_node_with(node: &Node, _ctx: &mut ParseContext) -> Self {
+        LocationLabel {
+            label_type: node.attribute("Type")
+                        .map(|e| e.to_string()),
+            value: text_content(node)
+        }
+    }
+}
+impl FromXMLNode for ItemList {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        ItemList {
+            list_type: node.attribute("ListType")
+                       .map(|e| e.to_string()),
+            items: list_items(node, "Item", ctx)
+                .iter()
+                .filter_map(text_content)
+                .collect()
+        }
+    }
+}
+impl FromXMLNode for Section {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut section = Section {
+            location_label: None,
+            section_title: None,
+            sections: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "LocationLabel" => section.location_label = Some(LocationLabel::from_node_with(&elem, ctx)),
+                    "SectionTitle" => section.section_title = text_content(&elem),
+                    "Section" => section.sections.push(Section::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        section
+    }
+}
+impl FromXMLNode for Book {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut book = Book {
+            publisher: None,
+            book_title: None,
+            pub_date: None,
+            beginning_date: None,
+            ending_date: None,
+            author_lists: Vec::new(),
+            investigator_list: Vec::new(),
+            volume: None,
+            volume_titles: Vec::new(),
+            edition: None,
+            collection_title: None,
+            isbns: Vec::new(),
+            elocation_ids: Vec::new(),
+            medium: None,
+            report_number: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "Publisher" => book.publisher = Some(Publisher::from_node_with(&elem, ctx)),
+                    "BookTitle" => book.book_title = text_content(&elem),
+                    "PubDate" => book.pub_date = Some(PubDate::from_node_with(&elem, ctx)),
+                    "BeginningDate" => book.beginning_date = Some(PubDate::from_node_with(&elem, ctx)),
+                    "EndingDate" => book.ending_date = Some(PubDate::from_node_with(&elem, ctx)),
+                    "AuthorList" => book.author_lists.push(AuthorList::from_node_with(&elem, ctx)),
+                    "InvestigatorList" => {
+                        for investigator in list_items(&elem, "Investigator", ctx) {
+                            book.investigator_list.push(Author::from_node_with(&investigator, ctx))
+                        }
+                    },
+                    "Volume" => book.volume = text_content(&elem),
+                    "VolumeTitle" => {
+                        if let Some(t) = text_content(&elem) {
+                            book.volume_titles.push(t)
+                        }
+                    },
+                    "Edition" => book.edition = text_content(&elem),
+                    "CollectionTitle" => book.collection_title = text_content(&elem),
+                    "Isbn" => {
+                        if let Some(t) = text_content(&elem) {
+                            book.isbns.push(t)
+                        }
+                    },
+                    "ELocationID" => book.elocation_ids.push(ELocationID::from_node_with(&elem, ctx)),
+                    "Medium" => book.medium = text_content(&elem),
+                    "ReportNumber" => book.report_number = text_content(&elem),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        book
+    }
+}
+impl FromXMLNode for BookDocument {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut book_document = BookDocument {
+            pmid: None,
+            article_id_list: None,
+            book: None,
+            location_labels: Vec::new(),
+            article_title: None,
+            article_title_markup: None,
+            vernacular_title: None,
+            pagination: None,
+            languages: Vec::new(),
+            author_lists: Vec::new(),
+            investigator_list: Vec::new(),
+            publication_type_list: Vec::new(),
+            abstract_text: None,
+            sections: Vec::new(),
+            keyword_lists: Vec::new(),
+            contribution_date: None,
+            date_revised: None,
+            citation_string: None,
+            grant_list: None,
+            item_lists: Vec::new(),
+            reference_lists: Vec::new()
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "PMID" => book_document.pmid = Some(PMID::from_node_with(&elem, ctx)),
+                    "ArticleIdList" => book_document.article_id_list = Some(ArticleIdList::from_node_with(&elem, ctx)),
+                    "Book" => book_document.book = Some(Book::from_node_with(&elem, ctx)),
+                    "LocationLabel" => book_document.location_labels.push(LocationLabel::from_node_with(&elem, ctx)),
+                    "ArticleTitle" => {
+                        let markup = Markup::from_node_with(&elem, ctx);
+                        book_document.article_title = Some(markup.to_plain_text());
+                        book_document.article_title_markup = Some(markup)
+                    },
+                    "VernacularTitle" => book_document.vernacular_title = text_content(&elem),
+                    "Pagination" => book_document.pagination = Some(Pagination::from_node_with(&elem, ctx)),
+                    "Language" => {
+                        if let Some(t) = text_content(&elem) {
+                            book_document.languages.push(t)
+                        }
+                    },
+                    "AuthorList" => book_document.author_lists.push(AuthorList::from_node_with(&elem, ctx)),
+                    "InvestigatorList" => {
+                        for investigator in list_items(&elem, "Investigator", ctx) {
+                            book_document.investigator_list.push(Author::from_node_with(&investigator, ctx))
+                        }
+                    },
+                    "PublicationType" => book_document.publication_type_list.push(PublicationType::from_node_with(&elem, ctx)),
+                    "Abstract" => book_document.abstract_text = Some(Abstract::from_node_with(&elem, ctx)),
+                    "Sections" => {
+                        for section in list_items(&elem, "Section", ctx) {
+                            book_document.sections.push(Section::from_node_with(&section, ctx))
+                        }
+                    },
+                    "KeywordList" => book_document.keyword_lists.push(KeywordList::from_node_with(&elem, ctx)),
+                    "ContributionDate" => book_document.contribution_date = Some(PubDate::from_node_with(&elem, ctx)),
+                    "DateRevised" => book_document.date_revised = Some(PubDate::from_node_with(&elem, ctx)),
+                    "CitationString" => book_document.citation_string = text_content(&elem),
+                    "GrantList" => book_document.grant_list = Some(GrantList::from_node_with(&elem, ctx)),
+                    "ItemList" => book_document.item_lists.push(ItemList::from_node_with(&elem, ctx)),
+                    "ReferenceList" => book_document.reference_lists.push(ReferenceList::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        book_document
+    }
+}
+impl FromXMLNode for PubmedBookArticle {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        let mut book_article = PubmedBookArticle {
+            book_document: None,
+            pubmed_book_data: None
+        };
+
+        for elem in node
+            .children()
+            .filter(|e| e.is_element()) {
+                match elem.tag_name().name() {
+                    "BookDocument" => book_article.book_document = Some(BookDocument::from_node_with(&elem, ctx)),
+                    "PubmedBookData" => book_article.pubmed_book_data = Some(PubmedData::from_node_with(&elem, ctx)),
+                    _ => ctx.unknown(&elem)
+                }
+        }
+
+        book_article
+    }
+}
+
 //PubmedArticleSet
 
 impl FromXMLNode for PubmedArticleSet {
-    fn from_node(node: &Node) -> Self {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
         let mut pm_set = PubmedArticleSet {
-            articles: Vec::new()
+            articles: Vec::new(),
+            deleted_citations: Vec::new()
         };
         
         for elem in node
@@ -701,9 +1904,18 @@ impl FromXMLNode for PubmedArticleSet {
             .filter(|e| e.is_element()) {
                 match elem.tag_name().name() { 
                     "PubmedArticle" => pm_set.articles.push(
-                        PubmedArticle::from_node(&elem)
+                        PubmedRecord::Article(Box::new(PubmedArticle::from_node_with(&elem, ctx)))
+                    ),
+                    "PubmedBookArticle" => pm_set.articles.push(
+                        PubmedRecord::BookArticle(Box::new(PubmedBookArticle::from_node_with(&elem, ctx)))
                     ),
-                    _ => ()
+                    "DeleteCitation" => {
+                        for pmid in list_items(&elem, "PMID", ctx) {
+                            let pmid = PMID::from_node_with(&pmid, ctx);
+                            pm_set.deleted_citations.push(pmid);
+                        }
+                    },
+                    _ => ctx.unknown(&elem)
                 }
         }
 
@@ -711,17 +1923,391 @@ impl FromXMLNode for PubmedArticleSet {
     }
 }
 
+impl PubDate {
+    /// The date as a typed range, parsed from `MedlineDate` when present and
+    /// otherwise built from `Year`, `Season`, `Month` and `Day`.
+    /// `None` if no year can be found.
+    pub fn range(&self) -> Option<DateRange> {
+        if let Some(medline_date) = &self.medline_date {
+            return parse_medline_date(medline_date);
+        }
+
+        let mut date = PartialDate::from_parts(self.year.as_deref(), self.month.as_deref(), self.day.as_deref())?;
+        date.season = self.season.as_deref().and_then(Season::parse);
+
+        Some(DateRange { start: date, end: date })
+    }
+
+    /// The start of the [range](PubDate::range).
+    pub fn date(&self) -> Option<PartialDate> {
+        self.range().map(|r| r.start)
+    }
+}
+
+impl PubMedPubDate {
+    pub fn date(&self) -> Option<PartialDate> {
+        PartialDate::from_parts(self.year.as_deref(), self.month.as_deref(), self.day.as_deref())
+    }
+}
+
+impl ArticleDate {
+    pub fn date(&self) -> Option<PartialDate> {
+        PartialDate::from_parts(self.year.as_deref(), self.month.as_deref(), self.day.as_deref())
+    }
+}
+
+impl PubmedData {
+    /// The date of the `History` entry with the given `PubStatus`
+    /// (e.g. `received`, `accepted`, `entrez`, `pubmed`, `medline`).
+    pub fn history_date(&self, pub_status: &str) -> Option<PartialDate> {
+        self.history
+            .iter()
+            .find(|d| d.pub_status.as_deref() == Some(pub_status))
+            .and_then(|d| d.date())
+    }
+
+    /// When the record was added to Entrez.
+    pub fn entrez_date(&self) -> Option<PartialDate> {
+        self.history_date("entrez")
+    }
+
+    /// When the record was added to PubMed.
+    pub fn pubmed_date(&self) -> Option<PartialDate> {
+        self.history_date("pubmed")
+    }
+
+    /// When the record was indexed for MEDLINE.
+    pub fn medline_date(&self) -> Option<PartialDate> {
+        self.history_date("medline")
+    }
+}
+
+impl Reference {
+    /// Splits the citation string into its parts with [parse_citation].
+    /// Mostly useful for references without an `ArticleIdList`.
+    pub fn parsed_citation(&self) -> Option<ParsedCitation> {
+        self.citation.as_deref().map(parse_citation)
+    }
+}
+
+impl ReferenceList {
+    /// The references of this list followed by those of its nested lists, depth first.
+    pub fn all_references(&self) -> Vec<&Reference> {
+        let mut references: Vec<&Reference> = self.references.iter().collect();
+        for list in &self.reference_lists {
+            references.extend(list.all_references());
+        }
+        references
+    }
+}
+
+impl Article {
+    /// Whether the article has a publication type with the given name
+    /// (e.g. `Randomized Controlled Trial`) or UI (e.g. `D016449`).
+    pub fn has_publication_type(&self, name_or_ui: &str) -> bool {
+        self.publication_type_list
+            .iter()
+            .any(|p| p.value.as_deref() == Some(name_or_ui) || p.ui.as_deref() == Some(name_or_ui))
+    }
+
+    /// Accession numbers from clinical trial registries such as ClinicalTrials.gov
+    /// (NCT numbers) or ISRCTN, in document order.
+    pub fn trial_registry_ids(&self) -> Vec<&str> {
+        self.data_bank_list
+            .iter()
+            .flat_map(|l| l.data_banks.iter())
+            .filter(|d| d.is_trial_registry())
+            .flat_map(|d| d.accession_numbers.iter().map(|n| n.as_str()))
+            .collect()
+    }
+}
+
+impl DataBank {
+    /// Whether the data bank is a clinical trial registry.
+    pub fn is_trial_registry(&self) -> bool {
+        self.data_bank_name
+            .as_deref()
+            .is_some_and(|name| TRIAL_REGISTRIES.contains(&name))
+    }
+}
+
+impl Chemical {
+    /// Classifies the registry number. Returns `None` when it is missing
+    /// or `0`, which MEDLINE uses for substances without a number.
+    pub fn registry_number_type(&self) -> Option<RegistryNumberType> {
+        let number = self.registry_number.as_deref()?.trim();
+
+        if number.is_empty() || number == "0" {
+            None
+        } else if number.starts_with("EC ") {
+            Some(RegistryNumberType::EC)
+        } else if is_cas_number(number) {
+            Some(RegistryNumberType::CAS)
+        } else if number.len() == 10 && number.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()) {
+            Some(RegistryNumberType::UNII)
+        } else {
+            Some(RegistryNumberType::Other)
+        }
+    }
+}
+
+/// Checks the `NNNNNNN-NN-N` layout and the check digit of a CAS registry number.
+fn is_cas_number(number: &str) -> bool {
+    let parts: Vec<&str> = number.split('-').collect();
+    if parts.len() != 3
+        || !(2..=7).contains(&parts[0].len())
+        || parts[1].len() != 2
+        || parts[2].len() != 1
+        || !parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit())) {
+        return false;
+    }
+
+    let digits: Vec<u32> = format!("{}{}", parts[0], parts[1])
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect();
+    let checksum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| (i as u32 + 1) * d)
+        .sum();
+
+    parts[2].chars().next().and_then(|c| c.to_digit(10)) == Some(checksum % 10)
+}
+
+impl MedlineCitation {
+    fn registry_numbers(&self, number_type: RegistryNumberType) -> Vec<&str> {
+        self.chemical_list
+            .iter()
+            .filter(|c| c.registry_number_type() == Some(number_type))
+            .filter_map(|c| c.registry_number.as_deref())
+            .collect()
+    }
+
+    /// CAS registry numbers of the indexed substances.
+    pub fn cas_registry_numbers(&self) -> Vec<&str> {
+        self.registry_numbers(RegistryNumberType::CAS)
+    }
+
+    /// FDA UNII codes of the indexed substances.
+    pub fn unii_codes(&self) -> Vec<&str> {
+        self.registry_numbers(RegistryNumberType::UNII)
+    }
+}
+
+impl MedlineCitation {
+    /// The comments and corrections with the given [RefType].
+    pub fn comments_corrections_of(&self, ref_type: &RefType) -> Vec<&CommentsCorrections> {
+        self.comments_corrections_list
+            .iter()
+            .filter(|c| c.ref_type.as_ref() == Some(ref_type))
+            .collect()
+    }
+
+    fn has_ref_type(&self, ref_types: &[RefType]) -> bool {
+        self.comments_corrections_list
+            .iter()
+            .any(|c| c.ref_type.as_ref().is_some_and(|t| ref_types.contains(t)))
+    }
+}
+
+impl PubmedArticle {
+    /// The earliest of the journal issue date and the article's `ArticleDate`s
+    /// (usually the electronic publication date).
+    pub fn earliest_publication_date(&self) -> Option<PartialDate> {
+        let article = self.medline_citation.as_ref()?.article.as_ref()?;
+        let issue_date = article.journal
+            .as_ref()
+            .and_then(|j| j.journal_issue.as_ref())
+            .and_then(|i| i.pub_date.as_ref())
+            .and_then(|d| d.date());
+
+        article.article_dates
+            .iter()
+            .filter_map(|d| d.date())
+            .chain(issue_date)
+            .min()
+    }
+
+    fn has_ref_type(&self, ref_types: &[RefType]) -> bool {
+        self.medline_citation
+            .as_ref()
+            .is_some_and(|m| m.has_ref_type(ref_types))
+    }
+
+    /// Whether the article has been retracted, either through a linked
+    /// retraction notice or the `Retracted Publication` publication type.
+    pub fn is_retracted(&self) -> bool {
+        let retracted_type = self.medline_citation
+            .as_ref()
+            .and_then(|m| m.article.as_ref())
+            .is_some_and(|a| a.has_publication_type("Retracted Publication"));
+
+        retracted_type
+            || self.has_ref_type(&[RefType::RetractionIn, RefType::RetractedAndRepublishedIn])
+    }
+
+    /// Whether the article is itself a retraction notice for another article.
+    pub fn is_retraction_notice(&self) -> bool {
+        self.has_ref_type(&[RefType::RetractionOf, RefType::RetractedAndRepublishedFrom])
+    }
+
+    /// Whether an erratum has been published for the article.
+    pub fn has_erratum(&self) -> bool {
+        self.has_ref_type(&[RefType::ErratumIn, RefType::CorrectedAndRepublishedIn])
+    }
+
+    /// Whether an expression of concern has been published about the article.
+    pub fn has_expression_of_concern(&self) -> bool {
+        self.has_ref_type(&[RefType::ExpressionOfConcernIn])
+    }
+
+    /// Whether the article has been superseded by an update.
+    pub fn is_updated(&self) -> bool {
+        self.has_ref_type(&[RefType::UpdateIn])
+    }
+}
+
+impl Book {
+    /// Authors listed in the book's `editors` author lists.
+    pub fn editors(&self) -> Vec<&Author> {
+        self.author_lists
+            .iter()
+            .filter(|l| l.list_type.as_deref() == Some("editors"))
+            .flat_map(|l| l.authors.iter())
+            .collect()
+    }
+}
+
+impl PubmedRecord {
+    pub fn as_article(&self) -> Option<&PubmedArticle> {
+        match self {
+            PubmedRecord::Article(a) => Some(a.as_ref()),
+            PubmedRecord::BookArticle(_) => None
+        }
+    }
+
+    pub fn as_book_article(&self) -> Option<&PubmedBookArticle> {
+        match self {
+            PubmedRecord::Article(_) => None,
+            PubmedRecord::BookArticle(b) => Some(b.as_ref())
+        }
+    }
+
+    pub fn pmid(&self) -> Option<&PMID> {
+        match self {
+            PubmedRecord::Article(a) => a.medline_citation.as_ref()?.pmid.as_ref(),
+            PubmedRecord::BookArticle(b) => b.book_document.as_ref()?.pmid.as_ref()
+        }
+    }
+}
+
+impl MeshHeading {
+    /// Whether the descriptor or any of its qualifiers is flagged as a major topic.
+    pub fn is_major_topic(&self) -> bool {
+        let is_major = |yn: &Option<String>| yn.as_deref() == Some("Y");
+
+        self.descriptor_name.as_ref().is_some_and(|d| is_major(&d.major_topic_yn))
+            || self.qualifier_names.iter().any(|q| is_major(&q.major_topic_yn))
+    }
+
+    /// Renders the heading as `Descriptor/Qualifier` strings, one per qualifier,
+    /// or as the bare descriptor when it has no qualifiers.
+    pub fn to_strings(&self) -> Vec<String> {
+        let descriptor = match self.descriptor_name.as_ref().and_then(|d| d.value.as_deref()) {
+            Some(d) => d,
+            None => return Vec::new()
+        };
+
+        if self.qualifier_names.is_empty() {
+            return vec![descriptor.to_string()];
+        }
+
+        self.qualifier_names
+            .iter()
+            .filter_map(|q| q.value.as_deref())
+            .map(|q| format!("{}/{}", descriptor, q))
+            .collect()
+    }
+}
+
+impl MeshHeadingList {
+    /// Descriptor names of the headings indexed as major topics.
+    pub fn major_topics(&self) -> Vec<&str> {
+        self.mesh_headings
+            .iter()
+            .filter(|h| h.is_major_topic())
+            .filter_map(|h| h.descriptor_name.as_ref())
+            .filter_map(|d| d.value.as_deref())
+            .collect()
+    }
+
+    /// All headings rendered as `Descriptor/Qualifier` strings.
+    pub fn to_strings(&self) -> Vec<String> {
+        self.mesh_headings
+            .iter()
+            .flat_map(|h| h.to_strings())
+            .collect()
+    }
+}
+
 impl PubmedArticleSet {
+    /// The journal articles of the set, in document order.
+    pub fn journal_articles(&self) -> impl Iterator<Item = &PubmedArticle> {
+        self.articles.iter().filter_map(|r| r.as_article())
+    }
+
+    /// The Bookshelf records of the set, in document order.
+    pub fn book_articles(&self) -> impl Iterator<Item = &PubmedBookArticle> {
+        self.articles.iter().filter_map(|r| r.as_book_article())
+    }
+
+    /// Whether the set deletes the record with the given PMID, whatever its version.
+    pub fn is_deleted(&self, pmid: &str) -> bool {
+        self.deleted_citations
+            .iter()
+            .any(|p| p.value.as_deref() == Some(pmid))
+    }
+
     pub fn read(xml: &str) -> Result<Self, Error> {
+        Self::read_with(xml, ParseOptions::default()).map(|(set, _)| set)
+    }
 
-        let pm_parsed = Document::parse(&xml)?;
-        
-        let pm_art_node = &pm_parsed 
-              .descendants()
-              .find(|n| n.has_tag_name("PubmedArticleSet")).unwrap();
-        
-        let res = PubmedArticleSet::from_node(&pm_art_node);
+    /// Like [read](PubmedArticleSet::read), returning the elements the parser
+    /// does not know next to the parsed set, or failing on them in strict mode.
+    pub fn read_with(xml: &str, options: ParseOptions) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let span = ParseSpan::new("pubmed", xml.len());
+
+        let res = span.in_scope(|| -> Result<(Self, Vec<Diagnostic>), Error> {
+            let pm_parsed = parse_document(xml)?;
+            let root = check_root(&pm_parsed, "PubmedArticleSet")?;
+
+            let mut ctx = ParseContext::new(options);
+            let set = PubmedArticleSet::from_node_with(&root, &mut ctx);
+            Ok((set, ctx.finish()?))
+        })?;
         
+        span.finish(res.0.articles.len());
         Ok(res)
     }
+
+    /// Reads a whole document from `reader` and parses it, decompressing gzip input.
+    pub fn read_from<R: Read>(reader: R) -> Result<Self, Error> {
+        let mut xml = String::new();
+        baseline::decompress(BufReader::new(reader))?
+            .read_to_string(&mut xml)?;
+
+        PubmedArticleSet::read(&xml)
+    }
+
+    /// Parses the file at `path`, which may be plain or gzip compressed XML
+    /// such as the PubMed baseline and update files.
+    pub fn read_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let mut xml = String::new();
+        baseline::open(path)?
+            .read_to_string(&mut xml)?;
+
+        PubmedArticleSet::read(&xml)
+    }
 }
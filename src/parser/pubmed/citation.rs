@@ -0,0 +1,165 @@
+//! Best-effort parsing of free-text `<Citation>` strings.
+//!
+//! Many references in a `<ReferenceList>` come without an `<ArticleIdList>`,
+//! leaving only a citation string, usually in the Vancouver style used by NLM:
+//!
+//! ```text
+//! Redman CW, Sargent IL. Latest advances in understanding preeclampsia. Science. 2005;308(5728):1592-1594.
+//! ```
+//!
+//! [parse_citation] splits such strings into their parts. Citations in other styles
+//! are parsed as far as possible; fields that cannot be found are left as `None`.
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ParsedCitation {
+    pub authors: Vec<String>,
+    pub title: Option<String>,
+    pub journal: Option<String>,
+    pub year: Option<String>,
+    pub volume: Option<String>,
+    pub issue: Option<String>,
+    pub pages: Option<String>
+}
+
+/// Splits a citation string into authors, title, journal, year, volume, issue and pages.
+///
+/// # Example
+///
+/// ```
+/// use entrez_rs::parser::pubmed::parse_citation;
+///
+/// let parsed = parse_citation("Redman CW, Sargent IL. Latest advances in understanding preeclampsia. Science. 2005;308(5728):1592-1594.");
+///
+/// assert_eq!(parsed.authors, vec!["Redman CW", "Sargent IL"]);
+/// assert_eq!(parsed.journal.as_deref(), Some("Science"));
+/// assert_eq!(parsed.pages.as_deref(), Some("1592-1594"));
+/// ```
+pub fn parse_citation(citation: &str) -> ParsedCitation {
+    let mut parsed = ParsedCitation::default();
+    let segments = split_segments(citation.trim());
+
+    let date_index = match segments.iter().rposition(|s| find_year(s).is_some()) {
+        Some(i) if i > 0 => i,
+        _ => {
+            // Without a date, only the author and title segments can be told apart.
+            let mut segments = segments.into_iter();
+            if let Some(authors) = segments.next() {
+                parsed.authors = split_authors(&authors);
+            }
+            parsed.title = join(segments.collect());
+            return parsed;
+        }
+    };
+
+    parsed.authors = split_authors(&segments[0]);
+
+    let source = &segments[date_index];
+    let year_at = find_year(source).unwrap();
+    let prefix = source[..year_at].trim_end_matches([' ', ';', ',', '.']);
+    let rest = &source[year_at..];
+    parsed.year = Some(rest[..4].to_string());
+
+    let mut title_end = date_index;
+    if let Some((_, locator)) = rest.split_once(';') {
+        parse_locator(locator, &mut parsed);
+
+        // A volume means a journal article: the journal name either precedes the
+        // year in the same segment or is the previous segment.
+        if !prefix.is_empty() {
+            parsed.journal = Some(prefix.to_string());
+        } else if date_index > 1 {
+            title_end -= 1;
+            parsed.journal = Some(segments[title_end].clone());
+        }
+    } else if prefix.is_empty() && date_index > 1 {
+        title_end -= 1;
+        parsed.journal = Some(segments[title_end].clone());
+    }
+
+    parsed.title = join(segments[1..title_end].to_vec());
+    parsed
+}
+
+// Splits on sentence boundaries, keeping `?` and `!` which belong to titles.
+fn split_segments(citation: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut chars = citation.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let boundary = matches!(c, '.' | '?' | '!') && chars.peek().is_none_or(|n| n.is_whitespace());
+        if !boundary {
+            current.push(c);
+            continue;
+        }
+        if c != '.' {
+            current.push(c);
+        }
+        let segment = current.trim();
+        if !segment.is_empty() {
+            segments.push(segment.to_string());
+        }
+        current.clear();
+    }
+
+    let segment = current.trim();
+    if !segment.is_empty() {
+        segments.push(segment.to_string());
+    }
+    segments
+}
+
+fn split_authors(segment: &str) -> Vec<String> {
+    segment
+        .split(',')
+        .map(|a| a.trim())
+        .filter(|a| !a.is_empty() && *a != "et al")
+        .map(|a| a.to_string())
+        .collect()
+}
+
+// Byte offset of the first plausible publication year (1800-2099) in `segment`.
+fn find_year(segment: &str) -> Option<usize> {
+    let bytes = segment.as_bytes();
+    (0..bytes.len().saturating_sub(3)).find(|&i| {
+        let candidate = &bytes[i..i + 4];
+        candidate.iter().all(u8::is_ascii_digit)
+            && (candidate.starts_with(b"18") || candidate.starts_with(b"19") || candidate.starts_with(b"20"))
+            && (i == 0 || !bytes[i - 1].is_ascii_digit())
+            && bytes.get(i + 4).is_none_or(|b| !b.is_ascii_digit())
+    })
+}
+
+// Parses `308(5728):1592-1594`, the part after the `;` in a journal reference.
+fn parse_locator(locator: &str, parsed: &mut ParsedCitation) {
+    let (volume_issue, pages) = match locator.split_once(':') {
+        Some((volume_issue, pages)) => (volume_issue, Some(pages)),
+        None => (locator, None)
+    };
+
+    let (volume, issue) = match volume_issue.split_once('(') {
+        Some((volume, issue)) => (volume, Some(issue.trim_end_matches(')'))),
+        None => (volume_issue, None)
+    };
+
+    parsed.volume = non_empty(volume);
+    parsed.issue = issue.and_then(non_empty);
+    parsed.pages = pages.and_then(non_empty);
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim().trim_end_matches('.');
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn join(segments: Vec<String>) -> Option<String> {
+    if segments.is_empty() {
+        None
+    } else {
+        Some(segments.join(". "))
+    }
+}
@@ -0,0 +1,269 @@
+//! Typed publication dates.
+//!
+//! A `<PubDate>` holds either `Year`/`Month`/`Day`/`Season` elements or a free-form
+//! `<MedlineDate>` such as `2020 Jan-Feb`, `Winter 2019` or `1998 Dec-1999 Jan`.
+//! Both are normalised into a [DateRange] whose ends are [PartialDate]s,
+//! each knowing how precise it is. With the `chrono` feature, [PartialDate]s
+//! convert to `chrono::NaiveDate`.
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DatePrecision {
+    Year,
+    Season,
+    Month,
+    Day
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter
+}
+
+impl Season {
+    /// Parses `Spring`, `Summer`, `Autumn`/`Fall` and `Winter`, ignoring case.
+    pub fn parse(name: &str) -> Option<Season> {
+        match name.to_ascii_lowercase().as_str() {
+            "spring" => Some(Season::Spring),
+            "summer" => Some(Season::Summer),
+            "autumn" | "fall" => Some(Season::Autumn),
+            "winter" => Some(Season::Winter),
+            _ => None
+        }
+    }
+
+    /// The first month of the season. Winter issues are dated by the year
+    /// they mostly fall in, so winter starts in January.
+    pub fn first_month(&self) -> u8 {
+        match self {
+            Season::Winter => 1,
+            Season::Spring => 3,
+            Season::Summer => 6,
+            Season::Autumn => 9
+        }
+    }
+}
+
+/// A date known to the precision of a year, a season, a month or a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialDate {
+    pub year: u16,
+    pub season: Option<Season>,
+    pub month: Option<u8>,
+    pub day: Option<u8>
+}
+
+impl PartialDate {
+    /// Builds a date from the text of `Year`, `Month` and `Day` elements.
+    /// Months may be names (`Jan`) or numbers (`01`). `None` if the year is missing or invalid.
+    pub fn from_parts(year: Option<&str>, month: Option<&str>, day: Option<&str>) -> Option<PartialDate> {
+        let month = month.and_then(|m| parse_month(m.trim()));
+        let day = day
+            .and_then(|d| d.trim().parse().ok())
+            .filter(|d| (1..=31).contains(d));
+
+        Some(PartialDate {
+            year: year?.trim().parse().ok()?,
+            season: None,
+            month,
+            day: month.and(day)
+        })
+    }
+
+    /// The first day covered by the date, as `(year, month, day)`.
+    pub fn first_day(&self) -> (u16, u8, u8) {
+        let month = self.month
+            .or_else(|| self.season.map(|s| s.first_month()))
+            .unwrap_or(1);
+        (self.year, month, self.day.unwrap_or(1))
+    }
+
+    /// The first day covered by the date, `None` if it does not exist (e.g. `Feb 30`).
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        let (year, month, day) = self.first_day();
+        chrono::NaiveDate::from_ymd_opt(year.into(), month.into(), day.into())
+    }
+
+    pub fn precision(&self) -> DatePrecision {
+        match (self.month, self.day, self.season) {
+            (Some(_), Some(_), _) => DatePrecision::Day,
+            (Some(_), None, _) => DatePrecision::Month,
+            (None, _, Some(_)) => DatePrecision::Season,
+            _ => DatePrecision::Year
+        }
+    }
+}
+
+/// Dates are ordered by their [first day](PartialDate::first_day),
+/// less precise dates first when they start on the same day.
+impl Ord for PartialDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.first_day()
+            .cmp(&other.first_day())
+            .then(self.precision().cmp(&other.precision()))
+            .then(self.season.map(|s| s as u8).cmp(&other.season.map(|s| s as u8)))
+    }
+}
+
+impl PartialOrd for PartialDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An inclusive range of dates. Single dates have the same `start` and `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: PartialDate,
+    pub end: PartialDate
+}
+
+impl DateRange {
+    /// The precision of the less precise end of the range.
+    pub fn precision(&self) -> DatePrecision {
+        self.start.precision().min(self.end.precision())
+    }
+
+    pub fn is_single(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// Parses a month given by name (`Jan`, `January`) or number (`1`, `01`).
+pub fn parse_month(month: &str) -> Option<u8> {
+    if let Ok(number) = month.parse::<u8>() {
+        return if (1..=12).contains(&number) { Some(number) } else { None };
+    }
+
+    const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    let month = month.to_ascii_lowercase();
+    MONTHS
+        .iter()
+        .position(|m| month.get(..3) == Some(*m) && is_month_name(&month))
+        .map(|i| i as u8 + 1)
+}
+
+fn is_month_name(month: &str) -> bool {
+    const NAMES: [&str; 13] = ["january", "february", "march", "april", "may", "june", "july",
+                               "august", "september", "sept", "october", "november", "december"];
+    NAMES.iter().any(|name| name.starts_with(month))
+}
+
+// The value of a two-digit year, e.g. the `99` of `1998-99`.
+fn short_year(text: &str) -> Option<u16> {
+    let text = text.trim();
+    if text.len() == 2 && text.chars().all(|c| c.is_ascii_digit()) {
+        text.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[derive(Default)]
+struct Parts {
+    year: Option<u16>,
+    season: Option<Season>,
+    month: Option<u8>,
+    day: Option<u8>
+}
+
+impl Parts {
+    fn parse(text: &str) -> Parts {
+        let mut parts = Parts::default();
+        for token in text.split_whitespace() {
+            let token = token.trim_matches([',', '.']);
+            if token.len() == 4 && token.chars().all(|c| c.is_ascii_digit()) {
+                parts.year = token.parse().ok();
+            } else if let Ok(day) = token.parse::<u8>() {
+                if (1..=31).contains(&day) {
+                    parts.day = Some(day);
+                }
+            } else if let Some(season) = Season::parse(token) {
+                parts.season = Some(season);
+            } else if let Some(month) = parse_month(token) {
+                parts.month = Some(month);
+            }
+        }
+        parts
+    }
+
+    fn into_date(self, year: u16) -> PartialDate {
+        PartialDate {
+            year,
+            season: self.season,
+            month: self.month,
+            day: self.month.and(self.day)
+        }
+    }
+}
+
+/// Parses a `<MedlineDate>` string into a [DateRange].
+///
+/// The second half of a range inherits the year and month it does not state
+/// (`1999 Dec 12-25`), and a month range wrapping past December moves to the next year
+/// (`1998 Dec-Jan`). A two-digit end of a year range is expanded against the start
+/// year (`1998-99`, `1999-00`). Unrecognised words are ignored; `None` is returned
+/// when no year is found.
+///
+/// # Example
+///
+/// ```
+/// use entrez_rs::parser::pubmed::{parse_medline_date, DatePrecision};
+///
+/// let range = parse_medline_date("1998 Dec-1999 Jan").unwrap();
+///
+/// assert_eq!((range.start.year, range.start.month), (1998, Some(12)));
+/// assert_eq!((range.end.year, range.end.month), (1999, Some(1)));
+/// assert_eq!(range.precision(), DatePrecision::Month);
+/// ```
+pub fn parse_medline_date(medline_date: &str) -> Option<DateRange> {
+    let (left, right) = match medline_date.split_once('-') {
+        Some((left, right)) => (left, Some(right)),
+        None => (medline_date, None)
+    };
+
+    let start = Parts::parse(left);
+    let end = match right {
+        Some(right) => match (start.year, start.month, start.day, short_year(right)) {
+            (Some(from), None, None, Some(to)) => {
+                let mut year = from - from % 100 + to;
+                if year < from {
+                    year += 100;
+                }
+                Parts { year: Some(year), ..Parts::default() }
+            },
+            _ => Parts::parse(right)
+        },
+        None => {
+            let year = start.year?;
+            let date = start.into_date(year);
+            return Some(DateRange { start: date, end: date });
+        }
+    };
+
+    let start_year = start.year.or(end.year)?;
+    let mut end_year = end.year.unwrap_or(start_year);
+    if end.year.is_none() {
+        if let (Some(from), Some(to)) = (start.month, end.month) {
+            if to < from {
+                end_year += 1;
+            }
+        }
+    }
+
+    let end_month = match (end.month, end.season, end.day) {
+        (None, None, Some(_)) => start.month,
+        _ => end.month
+    };
+    let end = Parts { month: end_month, ..end };
+
+    Some(DateRange {
+        start: start.into_date(start_year),
+        end: end.into_date(end_year)
+    })
+}
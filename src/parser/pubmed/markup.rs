@@ -0,0 +1,228 @@
+//! Inline markup of titles and abstracts.
+//!
+//! PubMed keeps a few formatting elements in `<ArticleTitle>` and `<AbstractText>`:
+//! `<i>`, `<b>`, `<u>`, `<sup>`, `<sub>` and MathML `<math>`, which may be nested
+//! (e.g. `<i>n<sup>2</sup></i>`). They are parsed into a tree of [Inline] nodes
+//! wrapped in [Markup], which renders to plain text, HTML or Markdown.
+
+use roxmltree::Node;
+use super::{text_content, FromXMLNode, ParseContext};
+
+const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    Text(String),
+    Italic(Vec<Inline>),
+    Bold(Vec<Inline>),
+    Underline(Vec<Inline>),
+    Sup(Vec<Inline>),
+    Sub(Vec<Inline>),
+    /// A `<math>` element. `markup` is the MathML without namespace prefixes,
+    /// ready to embed in HTML, and `text` its text content.
+    MathML {
+        markup: String,
+        text: String
+    },
+    /// Any other element, kept with its tag name.
+    Other {
+        tag: String,
+        children: Vec<Inline>
+    }
+}
+
+/// The inline content of an element.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Markup(pub Vec<Inline>);
+
+impl FromXMLNode for Markup {
+    fn from_node_with(node: &Node, ctx: &mut ParseContext) -> Self {
+        Markup(inlines(node, ctx))
+    }
+}
+
+// Elements other than the formatting ones are kept as `Inline::Other`
+// and reported to `ctx`.
+fn inlines(node: &Node, ctx: &mut ParseContext) -> Vec<Inline> {
+    node.children()
+        .filter_map(|child| {
+            if child.is_text() {
+                return child.text().map(|t| Inline::Text(t.to_string()));
+            }
+            if !child.is_element() {
+                return None;
+            }
+
+            let name = child.tag_name().name();
+            Some(match name {
+                "i" => Inline::Italic(inlines(&child, ctx)),
+                "b" => Inline::Bold(inlines(&child, ctx)),
+                "u" => Inline::Underline(inlines(&child, ctx)),
+                "sup" => Inline::Sup(inlines(&child, ctx)),
+                "sub" => Inline::Sub(inlines(&child, ctx)),
+                "math" => {
+                    let mut markup = String::new();
+                    write_mathml(&child, true, &mut markup);
+                    Inline::MathML {
+                        markup,
+                        text: text_content(&child).unwrap_or_default()
+                    }
+                },
+                _ => {
+                    ctx.unknown(&child);
+                    Inline::Other {
+                        tag: name.to_string(),
+                        children: inlines(&child, ctx)
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+fn write_mathml(node: &Node, root: bool, out: &mut String) {
+    if node.is_text() {
+        out.push_str(&escape_html(node.text().unwrap_or("")));
+        return;
+    }
+    if !node.is_element() {
+        return;
+    }
+
+    let name = node.tag_name().name();
+    out.push('<');
+    out.push_str(name);
+    if root {
+        out.push_str(" xmlns=\"");
+        out.push_str(MATHML_NAMESPACE);
+        out.push('"');
+    }
+    for attribute in node.attributes() {
+        out.push_str(&format!(" {}=\"{}\"", attribute.name(), escape_html(attribute.value())));
+    }
+    out.push('>');
+    for child in node.children() {
+        write_mathml(&child, false, out);
+    }
+    out.push_str("</");
+    out.push_str(name);
+    out.push('>');
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl Markup {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The text without any formatting.
+    pub fn to_plain_text(&self) -> String {
+        let mut out = String::new();
+        plain_text(&self.0, &mut out);
+        out
+    }
+
+    /// HTML using `<i>`, `<b>`, `<u>`, `<sup>` and `<sub>`, and MathML for formulas.
+    /// Unknown elements are rendered as their content.
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        html(&self.0, &mut out);
+        out
+    }
+
+    /// Markdown using `*` and `**` for italic and bold text. Underline, superscripts,
+    /// subscripts and formulas have no Markdown syntax and are written as inline HTML.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        markdown(&self.0, &mut out);
+        out
+    }
+}
+
+fn plain_text(inlines: &[Inline], out: &mut String) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(text) => out.push_str(text),
+            Inline::MathML { text, .. } => out.push_str(text),
+            Inline::Italic(children)
+            | Inline::Bold(children)
+            | Inline::Underline(children)
+            | Inline::Sup(children)
+            | Inline::Sub(children)
+            | Inline::Other { children, .. } => plain_text(children, out)
+        }
+    }
+}
+
+fn html(inlines: &[Inline], out: &mut String) {
+    for inline in inlines {
+        let (tag, children) = match inline {
+            Inline::Text(text) => {
+                out.push_str(&escape_html(text));
+                continue;
+            },
+            Inline::MathML { markup, .. } => {
+                out.push_str(markup);
+                continue;
+            },
+            Inline::Other { children, .. } => {
+                html(children, out);
+                continue;
+            },
+            Inline::Italic(children) => ("i", children),
+            Inline::Bold(children) => ("b", children),
+            Inline::Underline(children) => ("u", children),
+            Inline::Sup(children) => ("sup", children),
+            Inline::Sub(children) => ("sub", children)
+        };
+
+        out.push_str(&format!("<{}>", tag));
+        html(children, out);
+        out.push_str(&format!("</{}>", tag));
+    }
+}
+
+fn markdown(inlines: &[Inline], out: &mut String) {
+    for inline in inlines {
+        let (open, close, children) = match inline {
+            Inline::Text(text) => {
+                out.push_str(&escape_markdown(text));
+                continue;
+            },
+            Inline::MathML { markup, .. } => {
+                out.push_str(markup);
+                continue;
+            },
+            Inline::Other { children, .. } => {
+                markdown(children, out);
+                continue;
+            },
+            Inline::Italic(children) => ("*", "*", children),
+            Inline::Bold(children) => ("**", "**", children),
+            Inline::Underline(children) => ("<u>", "</u>", children),
+            Inline::Sup(children) => ("<sup>", "</sup>", children),
+            Inline::Sub(children) => ("<sub>", "</sub>", children)
+        };
+
+        out.push_str(open);
+        markdown(children, out);
+        out.push_str(close);
+    }
+}
@@ -0,0 +1,103 @@
+//! Parsing modes and reporting of unknown elements.
+//!
+//! The parsers only read the elements they know. When NCBI adds an element to
+//! the DTD, its content would be silently dropped, so every element a parser
+//! does not know is reported to a [ParseContext]. In [ParseMode::Lenient] mode
+//! they are collected into [Diagnostic]s; in [ParseMode::Strict] mode they make
+//! [read_with](super::PubmedArticleSet::read_with) fail.
+
+use roxmltree::Node;
+use super::super::super::errors::{Error, ParsingErrorType, TextPosition};
+use super::position;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Unknown elements are skipped and reported as [Diagnostic]s.
+    #[default]
+    Lenient,
+    /// Unknown elements are an [UnknownElement](ParsingErrorType::UnknownElement) error.
+    Strict
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParseOptions {
+    pub mode: ParseMode
+}
+
+impl ParseOptions {
+    pub fn strict() -> Self {
+        ParseOptions { mode: ParseMode::Strict }
+    }
+
+    pub fn lenient() -> Self {
+        ParseOptions { mode: ParseMode::Lenient }
+    }
+}
+
+/// An element the parser does not know, found `count` times at `path`
+/// (e.g. `PubmedArticleSet/PubmedArticle/MedlineCitation/NewElement`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub path: String,
+    pub count: usize
+}
+
+/// State shared by the [FromXMLNode](super::FromXMLNode) impls during one parse.
+#[derive(Debug, Default)]
+pub struct ParseContext {
+    options: ParseOptions,
+    diagnostics: Vec<Diagnostic>,
+    first_unknown: Option<(String, TextPosition)>
+}
+
+impl ParseContext {
+    pub fn new(options: ParseOptions) -> Self {
+        ParseContext {
+            options,
+            ..ParseContext::default()
+        }
+    }
+
+    /// Records an element the parser does not know.
+    pub fn unknown(&mut self, node: &Node) {
+        let mut names: Vec<&str> = node
+            .ancestors()
+            .filter(|n| n.is_element())
+            .map(|n| n.tag_name().name())
+            .collect();
+        names.reverse();
+        let path = names.join("/");
+
+        if self.options.mode == ParseMode::Strict && self.first_unknown.is_none() {
+            self.first_unknown = Some((path.clone(), position(node.document(), node)));
+        }
+
+        match self.diagnostics.iter_mut().find(|d| d.path == path) {
+            Some(diagnostic) => diagnostic.count += 1,
+            None => self.diagnostics.push(Diagnostic { path, count: 1 })
+        }
+    }
+
+    /// The unknown elements reported so far, in the order they were first found.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Ends the parse: the diagnostics in lenient mode, an error for the first
+    /// unknown element in strict mode.
+    pub fn finish(mut self) -> Result<Vec<Diagnostic>, Error> {
+        match self.take_error() {
+            Some(error) => Err(error),
+            None => Ok(self.diagnostics)
+        }
+    }
+
+    // The error for the first unknown element found in strict mode, if any.
+    pub(super) fn take_error(&mut self) -> Option<Error> {
+        self.first_unknown
+            .take()
+            .map(|(path, position)| Error::ParsingError(
+                ParsingErrorType::UnknownElement { path, position }
+            ))
+    }
+}
@@ -0,0 +1,123 @@
+//! Parallel parsing on the `rayon` thread pool, with the `rayon` feature.
+//!
+//! [par_read](PubmedArticleSet::par_read) splits a document into chunks of records
+//! with the streaming reader and parses the chunks in parallel, and
+//! [par_read_paths](PubmedArticleSet::par_read_paths) parses whole files in parallel.
+//! Both keep the input order.
+
+use std::mem;
+use std::path::Path;
+use rayon::prelude::*;
+use roxmltree::Document;
+use super::super::super::errors::Error;
+use super::super::super::instrument::ParseSpan;
+use super::super::baseline;
+use super::{Diagnostic, FromXMLNode, ParseContext, ParseOptions, PubmedArticleReader, PubmedArticleSet};
+
+// Records parsed together by one task. Large enough to amortise the
+// cost of a task, small enough to balance the load on a single file.
+const CHUNK_SIZE: usize = 256;
+
+impl PubmedArticleSet {
+    /// Like [read](PubmedArticleSet::read), parsing chunks of records in parallel.
+    pub fn par_read(xml: &str) -> Result<Self, Error> {
+        Self::par_read_with(xml, ParseOptions::default()).map(|(set, _)| set)
+    }
+
+    /// Like [read_with](PubmedArticleSet::read_with), parsing chunks of records in parallel.
+    /// The diagnostics are the same as `read_with`'s. In strict mode, the position
+    /// of an unknown element is relative to its chunk: the root start tag followed
+    /// by the chunk's records, the first one starting on line 1.
+    pub fn par_read_with(xml: &str, options: ParseOptions) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let span = ParseSpan::new("pubmed_parallel", xml.len());
+
+        let res = span.in_scope(|| -> Result<(Self, Vec<Diagnostic>), Error> {
+            let mut reader = PubmedArticleReader::new(xml.as_bytes());
+            let mut chunks = Vec::new();
+            let mut chunk = String::new();
+            let mut records = 0;
+
+            while let Some(raw) = reader.next_raw()? {
+                chunk.push_str(&raw);
+                records += 1;
+                if records == CHUNK_SIZE {
+                    chunks.push(reader.wrap(&mem::take(&mut chunk)));
+                    records = 0;
+                }
+            }
+            if records > 0 {
+                chunks.push(reader.wrap(&chunk));
+            }
+
+            // Collected before checking errors, so that strict mode
+            // reports the first unknown element of the document.
+            let parsed: Vec<_> = chunks
+                .par_iter()
+                .map(|chunk| parse_chunk(chunk, &options))
+                .collect();
+
+            let mut set = PubmedArticleSet { articles: Vec::new(), deleted_citations: Vec::new() };
+            let mut diagnostics = Vec::new();
+            for part in parsed {
+                let (part, part_diagnostics) = part?;
+                set.articles.extend(part.articles);
+                set.deleted_citations.extend(part.deleted_citations);
+                merge(&mut diagnostics, part_diagnostics);
+            }
+
+            Ok((set, diagnostics))
+        })?;
+
+        span.finish(res.0.articles.len());
+        Ok(res)
+    }
+
+    /// Parses files in parallel, returning one result per path in the same order.
+    /// Each file is read with a [PubmedArticleReader], so only one record
+    /// per thread is held as a document tree at a time.
+    pub fn par_read_paths<P: AsRef<Path> + Sync>(paths: &[P]) -> Vec<Result<Self, Error>> {
+        Self::par_read_paths_with(paths, ParseOptions::default())
+            .into_iter()
+            .map(|res| res.map(|(set, _)| set))
+            .collect()
+    }
+
+    /// Like [par_read_paths](PubmedArticleSet::par_read_paths), with the diagnostics of each file.
+    pub fn par_read_paths_with<P: AsRef<Path> + Sync>(paths: &[P], options: ParseOptions) -> Vec<Result<(Self, Vec<Diagnostic>), Error>> {
+        paths
+            .par_iter()
+            .map(|path| {
+                let span = ParseSpan::streaming("pubmed_parallel_file");
+                let mut reader = PubmedArticleReader::with_options(baseline::open(path)?, options.clone());
+                let articles = span.in_scope(|| (&mut reader).collect::<Result<Vec<_>, Error>>());
+
+                span.finish_stream(articles.as_ref().map_or(0, |a| a.len()), reader.bytes_read());
+                Ok((
+                    PubmedArticleSet {
+                        articles: articles?,
+                        deleted_citations: mem::take(&mut reader.deleted_citations)
+                    },
+                    reader.diagnostics().to_vec()
+                ))
+            })
+            .collect()
+    }
+}
+
+fn parse_chunk(xml: &str, options: &ParseOptions) -> Result<(PubmedArticleSet, Vec<Diagnostic>), Error> {
+    let document = Document::parse(xml)?;
+    let mut ctx = ParseContext::new(options.clone());
+    let set = PubmedArticleSet::from_node_with(&document.root_element(), &mut ctx);
+
+    Ok((set, ctx.finish()?))
+}
+
+// Adds the counts of `other` to `diagnostics`, keeping the order of first appearance.
+fn merge(diagnostics: &mut Vec<Diagnostic>, other: Vec<Diagnostic>) {
+    for diagnostic in other {
+        match diagnostics.iter_mut().find(|d| d.path == diagnostic.path) {
+            Some(existing) => existing.count += diagnostic.count,
+            None => diagnostics.push(diagnostic)
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Streaming parser for large PubMed XML files.
+//!
+//! [PubmedArticleSet::read](super::PubmedArticleSet::read) builds the whole document
+//! tree in memory, which takes several GB for a baseline file. [PubmedArticleReader]
+//! instead pulls events from a [BufRead] with quick-xml and only keeps the XML of
+//! one record at a time. Each record is parsed by the same [FromXMLNode] impls
+//! as the in-memory parser, so both produce identical records.
+//!
+//! # Example
+//!
+//!  ```no_run
+//!   use entrez_rs::parser::pubmed::PubmedArticleReader;
+//!
+//!   let mut reader = PubmedArticleReader::from_path("pubmed21n1062.xml.gz")
+//!                    .expect("Cannot open file");
+//!
+//!   for record in &mut reader {
+//!       let record = record.expect("Parsing error");
+//!       println!("{:?}", record.pmid());
+//!   }
+//!
+//!   println!("{} deleted citations", reader.deleted_citations().len());
+//!   ```
+
+use std::io::BufRead;
+use std::mem;
+use std::path::Path;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use roxmltree::Document;
+use super::super::super::errors::{Error, ParsingErrorType};
+use super::super::super::instrument::ParseSpan;
+use super::super::baseline;
+use super::{check_root, parse_document, Diagnostic, FromXMLNode, ParseContext, ParseOptions, PubmedArticleSet, PubmedRecord, PMID};
+
+const ROOT: &str = "PubmedArticleSet";
+
+/// An iterator over the records of a `<PubmedArticleSet>`, read one at a time.
+///
+/// `<DeleteCitation>` PMIDs are collected on the way and available from
+/// [deleted_citations](PubmedArticleReader::deleted_citations) once the records
+/// before them have been read. Iteration stops after the first error.
+///
+/// Iterating records a `tracing` span, with the `tracing` feature, that ends
+/// with the last record or the first error.
+///
+/// Each record is parsed in a document made of the root start tag, with its
+/// namespace declarations, followed by the record. Positions in errors are
+/// relative to that document, not to the whole input.
+pub struct PubmedArticleReader<R: BufRead> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    root: String,
+    ctx: ParseContext,
+    // Created by the first call to `next`, so that splitting a document
+    // with `next_raw` is not reported as a parse.
+    span: Option<ParseSpan>,
+    records: usize,
+    pub(super) deleted_citations: Vec<PMID>,
+    started: bool,
+    done: bool
+}
+
+impl<R: BufRead> PubmedArticleReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, ParseOptions::default())
+    }
+
+    pub fn with_options(reader: R, options: ParseOptions) -> Self {
+        let mut reader = Reader::from_reader(reader);
+        reader
+            .trim_text(false)
+            .expand_empty_elements(false)
+            .check_end_names(true);
+
+        PubmedArticleReader {
+            reader,
+            buf: Vec::new(),
+            root: format!("<{}>", ROOT),
+            ctx: ParseContext::new(options),
+            span: None,
+            records: 0,
+            deleted_citations: Vec::new(),
+            started: false,
+            done: false
+        }
+    }
+
+    /// The PMIDs of the `<DeleteCitation>` elements read so far.
+    pub fn deleted_citations(&self) -> &[PMID] {
+        &self.deleted_citations
+    }
+
+    /// The unknown elements found so far, see [ParseOptions].
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        self.ctx.diagnostics()
+    }
+
+    fn next_record(&mut self) -> Result<Option<PubmedRecord>, Error> {
+        while let Some(raw) = self.next_raw()? {
+            let xml = self.wrap(&raw);
+            let document = Document::parse(&xml)?;
+            let set = PubmedArticleSet::from_node_with(&document.root_element(), &mut self.ctx);
+
+            if let Some(error) = self.ctx.take_error() {
+                return Err(error);
+            }
+            self.deleted_citations.extend(set.deleted_citations);
+            if let Some(record) = set.articles.into_iter().next() {
+                return Ok(Some(record));
+            }
+        }
+
+        Ok(None)
+    }
+
+    // The XML of the next child of the root element, `None` after the last one.
+    pub(super) fn next_raw(&mut self) -> Result<Option<String>, Error> {
+        loop {
+            self.buf.clear();
+            let (start, empty) = match self.reader.read_event(&mut self.buf)? {
+                Event::Start(e) => (e.into_owned(), false),
+                Event::Empty(e) => (e.into_owned(), true),
+                Event::Eof if !self.started => return Err(Error::ParsingError(ParsingErrorType::MissingRoot)),
+                Event::Eof => return Ok(None),
+                _ => continue
+            };
+
+            if !self.started {
+                self.started = true;
+                if start.name() == ROOT.as_bytes() {
+                    let tag = std::str::from_utf8(&start).map_err(quick_xml::Error::Utf8)?;
+                    self.root = format!("<{}>", tag);
+                    continue;
+                }
+
+                // Let the in-memory parser report NCBI errors and unexpected roots.
+                let xml = self.capture(&start, empty)?;
+                let document = parse_document(&xml)?;
+                match check_root(&document, ROOT) {
+                    Err(e) => return Err(e),
+                    Ok(_) => continue
+                }
+            }
+
+            return self.capture(&start, empty).map(Some);
+        }
+    }
+
+    // The number of bytes read from the input so far.
+    pub(super) fn bytes_read(&self) -> usize {
+        self.reader.buffer_position()
+    }
+
+    // A document with the root start tag of the input around `records`,
+    // so that they can use the namespaces declared on the root.
+    pub(super) fn wrap(&self, records: &str) -> String {
+        format!("{}{}</{}>", self.root, records, ROOT)
+    }
+
+    // Reads the events up to the end of the element started by `start`
+    // and writes them back as XML.
+    fn capture(&mut self, start: &BytesStart, empty: bool) -> Result<String, Error> {
+        let mut record = vec![b'<'];
+        record.extend_from_slice(start);
+
+        if empty {
+            record.extend_from_slice(b"/>");
+        } else {
+            record.push(b'>');
+
+            let mut depth = 1;
+            let mut buf = mem::take(&mut self.buf);
+            while depth > 0 {
+                buf.clear();
+                match self.reader.read_event(&mut buf)? {
+                    Event::Start(e) => {
+                        depth += 1;
+                        record.push(b'<');
+                        record.extend_from_slice(&e);
+                        record.push(b'>');
+                    },
+                    Event::End(e) => {
+                        depth -= 1;
+                        record.extend_from_slice(b"</");
+                        record.extend_from_slice(&e);
+                        record.push(b'>');
+                    },
+                    Event::Empty(e) => {
+                        record.push(b'<');
+                        record.extend_from_slice(&e);
+                        record.extend_from_slice(b"/>");
+                    },
+                    Event::Text(e) => record.extend_from_slice(&e),
+                    Event::CData(e) => {
+                        record.extend_from_slice(b"<![CDATA[");
+                        record.extend_from_slice(&e);
+                        record.extend_from_slice(b"]]>");
+                    },
+                    Event::Eof => {
+                        return Err(quick_xml::Error::UnexpectedEof(
+                            String::from_utf8_lossy(start.name()).into_owned()
+                        ).into())
+                    },
+                    _ => ()
+                }
+            }
+            self.buf = buf;
+        }
+
+        String::from_utf8(record)
+            .map_err(|e| quick_xml::Error::Utf8(e.utf8_error()).into())
+    }
+}
+
+impl PubmedArticleReader<Box<dyn BufRead>> {
+    /// Opens a PubMed XML file, decompressing it if it is gzipped.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(Self::new(baseline::open(path)?))
+    }
+}
+
+impl<R: BufRead> Iterator for PubmedArticleReader<R> {
+    type Item = Result<PubmedRecord, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let span = self.span.take().unwrap_or_else(|| ParseSpan::streaming("pubmed_stream"));
+        let res = span.in_scope(|| self.next_record());
+
+        let item = match res {
+            Ok(Some(record)) => {
+                self.records += 1;
+                Some(Ok(record))
+            },
+            Ok(None) => None,
+            Err(e) => Some(Err(e))
+        };
+
+        if matches!(item, Some(Ok(_))) {
+            self.span = Some(span);
+        } else {
+            self.done = true;
+            span.finish_stream(self.records, self.bytes_read());
+        }
+        item
+    }
+}
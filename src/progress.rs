@@ -0,0 +1,100 @@
+//! Progress reporting for long-running E-utility workflows.
+
+//! # Description
+//! Batched downloads such as [EFetch::run_batches](super::eutils::EFetch::run_batches)
+//! report what they are doing to a [ProgressObserver] as a sequence of [ProgressEvent]s.
+//! [NoProgress] ignores every event and [StderrProgress] prints a line per event,
+//! which is enough for command line tools. GUIs can implement [ProgressObserver]
+//! to drive their own progress bars.
+//!
+//! # Example
+//!
+//!  ```no_run
+//!   # #[cfg(feature = "blocking")] {
+//!   use entrez_rs::eutils::{EFetch, DB};
+//!   use entrez_rs::progress::{StderrProgress};
+//!
+//!   let mut out = Vec::new();
+//!
+//!   EFetch::new(
+//!         DB::Pubmed,
+//!         vec!["33246200", "33243171", "33242850"])
+//!         .run_batches(0, 2, &mut out, &mut StderrProgress::default())
+//!         .expect("Connection error");
+//!   # }
+//!   ```
+
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgressEvent {
+    /// The total number of records the workflow is going to fetch.
+    TotalCount(u64),
+    /// A request for `size` records starting at `retstart` was sent.
+    BatchStarted {
+        index: usize,
+        retstart: u64,
+        size: u64
+    },
+    /// A batch was fully received, `bytes` being the size of its body.
+    BatchFinished {
+        index: usize,
+        bytes: u64
+    },
+    /// A chunk of a response body was received.
+    BytesReceived(u64),
+    /// A failed request is about to be retried.
+    Retry {
+        attempt: u32,
+        error: String
+    },
+    /// The workflow is sleeping to stay within the NCBI request rate limits.
+    ThrottleWait(Duration)
+}
+
+pub trait ProgressObserver {
+    fn on_event(&mut self, event: &ProgressEvent);
+}
+
+/// An observer that ignores every event.
+#[derive(Debug, Default)]
+pub struct NoProgress;
+
+impl ProgressObserver for NoProgress {
+    fn on_event(&mut self, _event: &ProgressEvent) {}
+}
+
+/// An observer that writes a line to stderr for every event,
+/// except [BytesReceived](ProgressEvent::BytesReceived) which is only totalled.
+#[derive(Debug, Default)]
+pub struct StderrProgress {
+    pub total: Option<u64>,
+    pub bytes: u64
+}
+
+impl ProgressObserver for StderrProgress {
+    fn on_event(&mut self, event: &ProgressEvent) {
+        match event {
+            ProgressEvent::TotalCount(n) => {
+                self.total = Some(*n);
+                eprintln!("entrez: {} records to fetch", n)
+            },
+            ProgressEvent::BatchStarted { index, retstart, size } => {
+                match self.total {
+                    Some(total) => eprintln!("entrez: batch {} started ({}-{} of {})", index + 1, retstart + 1, retstart + size, total),
+                    None => eprintln!("entrez: batch {} started ({}-{})", index + 1, retstart + 1, retstart + size)
+                }
+            },
+            ProgressEvent::BatchFinished { index, bytes } => {
+                eprintln!("entrez: batch {} finished ({} bytes, {} bytes total)", index + 1, bytes, self.bytes)
+            },
+            ProgressEvent::BytesReceived(n) => self.bytes += n,
+            ProgressEvent::Retry { attempt, error } => {
+                eprintln!("entrez: retry {} after error: {}", attempt, error)
+            },
+            ProgressEvent::ThrottleWait(wait) => {
+                eprintln!("entrez: waiting {} ms", wait.as_millis())
+            }
+        }
+    }
+}
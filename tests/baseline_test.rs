@@ -0,0 +1,66 @@
+extern crate entrez_rs;
+extern crate flate2;
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use entrez_rs::parser::baseline;
+use entrez_rs::parser::pubmed::{PubmedArticleReader, PubmedArticleSet};
+use entrez_rs::errors::Error;
+
+const FIXTURE: &str = include_str!("fixtures/pubmed_articles.xml");
+
+fn write_gzip(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    let mut encoder = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+    encoder.write_all(FIXTURE.as_bytes()).unwrap();
+    encoder.finish().unwrap();
+    path
+}
+
+#[test]
+fn read_gzip_path_test() -> Result<(), Error> {
+    let path = write_gzip("entrez_rs_read_gzip_path.xml.gz");
+
+    let from_gzip = PubmedArticleSet::read_path(&path)?;
+    let from_str = PubmedArticleSet::read(FIXTURE)?;
+
+    assert_eq!(from_gzip.articles.len(), 2);
+    assert_eq!(from_gzip, from_str);
+    assert_eq!(PubmedArticleSet::read_from(FIXTURE.as_bytes())?, from_str);
+
+    let streamed = PubmedArticleReader::from_path(&path)?.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(streamed, from_str.articles);
+
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+#[test]
+fn verify_md5_test() -> Result<(), Error> {
+    let path = write_gzip("entrez_rs_verify_md5.xml.gz");
+    let digest = baseline::md5_hex(File::open(&path)?)?;
+
+    fs::write(baseline::md5_path(&path), format!("MD5(entrez_rs_verify_md5.xml.gz)= {}\n", digest))?;
+    baseline::verify_md5(&path)?;
+
+    fs::write(baseline::md5_path(&path), "MD5(entrez_rs_verify_md5.xml.gz)= 00000000000000000000000000000000\n")?;
+    match baseline::verify_md5(&path) {
+        Err(Error::ChecksumMismatch { actual, .. }) => assert_eq!(actual, digest),
+        other => panic!("expected a checksum mismatch, got {:?}", other)
+    }
+
+    for content in ["", "MD5(entrez_rs_verify_md5.xml.gz)= not-a-digest\n"].iter() {
+        fs::write(baseline::md5_path(&path), content)?;
+        match baseline::verify_md5(&path) {
+            Err(Error::InvalidChecksumFile(companion)) => assert_eq!(companion, baseline::md5_path(&path)),
+            other => panic!("expected an invalid checksum file, got {:?}", other)
+        }
+    }
+
+    fs::remove_file(baseline::md5_path(&path))?;
+    fs::remove_file(path)?;
+    Ok(())
+}
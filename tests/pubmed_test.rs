@@ -0,0 +1,813 @@
+extern crate entrez_rs;
+
+use entrez_rs::parser::pubmed::*;
+
+const FIXTURE: &str = include_str!("fixtures/pubmed_articles.xml");
+const BOOK_FIXTURE: &str = include_str!("fixtures/pubmed_book_articles.xml");
+
+// The book fixture with an element that is not in the DTD, on line 19, column 13.
+fn book_fixture_with_unknown_element() -> String {
+    BOOK_FIXTURE
+        .replace("<BeginningDate>", "<StartingDate>")
+        .replace("</BeginningDate>", "</StartingDate>")
+}
+
+fn citation(set: &PubmedArticleSet, i: usize) -> &MedlineCitation {
+    set.articles[i]
+        .as_article()
+        .expect("PubmedArticle")
+        .medline_citation
+        .as_ref()
+        .expect("MedlineCitation")
+}
+
+fn article(set: &PubmedArticleSet, i: usize) -> &Article {
+    citation(set, i)
+        .article
+        .as_ref()
+        .expect("Article")
+}
+
+#[test]
+fn author_list_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let author_list = article(&set, 0).author_list.as_ref().unwrap();
+
+    assert_eq!(author_list.complete_yn.as_deref(), Some("Y"));
+    assert_eq!(author_list.authors.len(), 3);
+
+    let smith = &author_list.authors[0];
+    assert_eq!(smith.last_name.as_deref(), Some("Smith"));
+    assert_eq!(smith.fore_name.as_deref(), Some("Jane A"));
+    assert_eq!(smith.initials.as_deref(), Some("JA"));
+    assert_eq!(smith.suffix.as_deref(), Some("Jr"));
+    assert_eq!(smith.equal_contrib.as_deref(), Some("Y"));
+    assert_eq!(smith.valid_yn.as_deref(), Some("Y"));
+    assert_eq!(smith.identifiers, vec![Identifier {
+        source: Some("ORCID".to_string()),
+        value: Some("0000-0002-1825-0097".to_string())
+    }]);
+    assert_eq!(smith.affiliation_info.len(), 2);
+    assert_eq!(smith.affiliation_info[0].identifiers[0].source.as_deref(), Some("ROR"));
+    assert_eq!(smith.affiliation_info[0].identifiers[0].value.as_deref(), Some("https://ror.org/052gg0110"));
+    assert!(smith.affiliation_info[1].identifiers.is_empty());
+
+    let group = &author_list.authors[2];
+    assert_eq!(group.collective_name.as_deref(), Some("PREMOTHER Study Group"));
+    assert_eq!(group.last_name, None);
+
+    let incomplete = article(&set, 1).author_list.as_ref().unwrap();
+    assert_eq!(incomplete.complete_yn.as_deref(), Some("N"));
+}
+
+#[test]
+fn mesh_heading_list_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let mesh = citation(&set, 0).mesh_heading_list.as_ref().unwrap();
+
+    assert_eq!(mesh.mesh_headings.len(), 3);
+
+    let placenta = &mesh.mesh_headings[1];
+    let descriptor = placenta.descriptor_name.as_ref().unwrap();
+    assert_eq!(descriptor.ui.as_deref(), Some("D010920"));
+    assert_eq!(descriptor.major_topic_yn.as_deref(), Some("N"));
+    assert_eq!(placenta.qualifier_names[0].ui.as_deref(), Some("Q000378"));
+    assert_eq!(placenta.qualifier_names[0].major_topic_yn.as_deref(), Some("Y"));
+
+    let pre_eclampsia = mesh.mesh_headings[2].descriptor_name.as_ref().unwrap();
+    assert_eq!(pre_eclampsia.descriptor_type.as_deref(), Some("Geographic"));
+
+    assert_eq!(mesh.major_topics(), vec!["Placenta", "Pre-Eclampsia"]);
+    assert_eq!(mesh.to_strings(), vec![
+        "Humans",
+        "Placenta/metabolism",
+        "Placenta/pathology",
+        "Pre-Eclampsia"
+    ]);
+
+    assert_eq!(citation(&set, 1).mesh_heading_list, None);
+}
+
+#[test]
+fn keyword_publication_type_grant_lists_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+
+    let keyword_lists = &citation(&set, 0).keyword_lists;
+    assert_eq!(keyword_lists.len(), 1);
+    assert_eq!(keyword_lists[0].owner.as_deref(), Some("NOTNLM"));
+    assert_eq!(keyword_lists[0].keywords[1], Keyword {
+        major_topic_yn: Some("Y".to_string()),
+        value: Some("Pre-eclampsia".to_string())
+    });
+
+    let rct = article(&set, 0);
+    assert_eq!(rct.publication_type_list.len(), 2);
+    assert_eq!(rct.publication_type_list[0].ui.as_deref(), Some("D016449"));
+    assert!(rct.has_publication_type("Randomized Controlled Trial"));
+    assert!(rct.has_publication_type("D013485"));
+    assert!(!rct.has_publication_type("Review"));
+    assert!(article(&set, 1).has_publication_type("Retracted Publication"));
+
+    let grant_list = rct.grant_list.as_ref().unwrap();
+    assert_eq!(grant_list.complete_yn.as_deref(), Some("Y"));
+    assert_eq!(grant_list.grants[0], Grant {
+        grant_id: Some("R01 HD012345".to_string()),
+        acronym: Some("HD".to_string()),
+        agency: Some("NICHD NIH HHS".to_string()),
+        country: Some("United States".to_string())
+    });
+    assert_eq!(grant_list.grants[1].acronym, None);
+    assert_eq!(article(&set, 1).grant_list, None);
+}
+
+#[test]
+fn chemical_suppl_mesh_gene_symbol_lists_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let medline = citation(&set, 0);
+
+    assert_eq!(medline.chemical_list.len(), 4);
+    assert_eq!(medline.chemical_list[1], Chemical {
+        registry_number: Some("142M471B3J".to_string()),
+        name_of_substance: Some(NameOfSubstance {
+            ui: Some("D002245".to_string()),
+            value: Some("Carbon Dioxide".to_string())
+        })
+    });
+    assert_eq!(medline.chemical_list[0].registry_number_type(), None);
+    assert_eq!(medline.chemical_list[3].registry_number_type(), Some(RegistryNumberType::EC));
+    assert_eq!(medline.cas_registry_numbers(), vec!["50-99-7"]);
+    assert_eq!(medline.unii_codes(), vec!["142M471B3J"]);
+
+    assert_eq!(medline.suppl_mesh_list[0], SupplMeshName {
+        suppl_type: Some("Disease".to_string()),
+        ui: Some("C538231".to_string()),
+        value: Some("HELLP syndrome, familial".to_string())
+    });
+    assert_eq!(medline.suppl_mesh_list[1].suppl_type.as_deref(), Some("Protocol"));
+
+    assert_eq!(medline.gene_symbol_list, vec!["FLT1", "VEGFR1"]);
+    assert!(citation(&set, 1).chemical_list.is_empty());
+}
+
+#[test]
+fn comments_corrections_list_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let medline = citation(&set, 0);
+
+    assert_eq!(medline.comments_corrections_list.len(), 3);
+
+    let erratum = &medline.comments_corrections_list[1];
+    assert_eq!(erratum.ref_type, Some(RefType::ErratumIn));
+    assert_eq!(erratum.ref_source.as_deref(), Some("Placenta. 2021 Apr;106:90"));
+    assert_eq!(erratum.pmid.as_ref().and_then(|p| p.value.as_deref()), Some("33600000"));
+    assert_eq!(erratum.note.as_deref(), Some("Smith JA [corrected to Smith JA Jr]"));
+
+    let retractions = medline.comments_corrections_of(&RefType::RetractionIn);
+    assert_eq!(retractions.len(), 1);
+    assert_eq!(RefType::from("SomethingNew"), RefType::Other("SomethingNew".to_string()));
+
+    let first = set.articles[0].as_article().unwrap();
+    assert!(first.is_retracted());
+    assert!(first.has_erratum());
+    assert!(!first.is_retraction_notice());
+    assert!(!first.has_expression_of_concern());
+
+    // Flagged through the publication type only.
+    let second = set.articles[1].as_article().unwrap();
+    assert!(second.is_retracted());
+    assert!(!second.has_erratum());
+}
+
+#[test]
+fn data_bank_list_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let data_bank_list = article(&set, 0).data_bank_list.as_ref().unwrap();
+
+    assert_eq!(data_bank_list.complete_yn.as_deref(), Some("Y"));
+    assert_eq!(data_bank_list.data_banks.len(), 3);
+    assert_eq!(data_bank_list.data_banks[1], DataBank {
+        data_bank_name: Some("GENBANK".to_string()),
+        accession_numbers: vec!["MN908947".to_string()]
+    });
+    assert!(!data_bank_list.data_banks[1].is_trial_registry());
+
+    assert_eq!(
+        article(&set, 0).trial_registry_ids(),
+        vec!["NCT01234567", "NCT07654321", "ISRCTN12345678"]
+    );
+    assert!(article(&set, 1).trial_registry_ids().is_empty());
+}
+
+#[test]
+fn pagination_article_date_vernacular_title_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let first = article(&set, 0);
+
+    assert_eq!(first.pagination, Some(Pagination {
+        start_page: None,
+        end_page: None,
+        medline_pgn: Some("141-148".to_string())
+    }));
+    assert_eq!(first.vernacular_title.as_deref(), Some("Expression de FLT1 dans le placenta."));
+    assert_eq!(first.article_dates, vec![ArticleDate {
+        date_type: Some("Electronic".to_string()),
+        year: Some("2020".to_string()),
+        month: Some("11".to_string()),
+        day: Some("21".to_string())
+    }]);
+
+    let second = article(&set, 1);
+    assert_eq!(second.pagination.as_ref().and_then(|p| p.medline_pgn.as_deref()), Some("1540-5"));
+    assert_eq!(second.vernacular_title, None);
+    assert!(second.article_dates.is_empty());
+}
+
+#[test]
+fn other_abstract_copyright_coi_statement_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let medline = citation(&set, 0);
+
+    let abstract_text = article(&set, 0).abstract_text.as_ref().unwrap();
+    assert_eq!(abstract_text.text.len(), 2);
+    assert_eq!(
+        abstract_text.copyright_information.as_deref(),
+        Some("Copyright © 2020 Elsevier Ltd. All rights reserved.")
+    );
+
+    assert_eq!(medline.other_abstracts.len(), 1);
+    let other = &medline.other_abstracts[0];
+    assert_eq!(other.abstract_type.as_deref(), Some("Publisher"));
+    assert_eq!(other.language.as_deref(), Some("fre"));
+    assert_eq!(other.text[0].value.as_deref(), Some("La pré-éclampsie touche 2 à 8 % des grossesses."));
+    assert_eq!(other.copyright_information.as_deref(), Some("© 2020 Elsevier Masson SAS."));
+
+    assert_eq!(
+        medline.coi_statement.as_deref(),
+        Some("Declaration of competing interest JAS reports grants from Roche, outside the submitted work.")
+    );
+    assert_eq!(citation(&set, 1).coi_statement, None);
+}
+
+#[test]
+fn investigators_personal_name_subjects_notes_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let medline = citation(&set, 0);
+
+    assert_eq!(medline.investigator_list.len(), 1);
+    let investigator = &medline.investigator_list[0];
+    assert_eq!(investigator.last_name.as_deref(), Some("Nguyen"));
+    assert_eq!(investigator.valid_yn.as_deref(), Some("Y"));
+    assert_eq!(investigator.identifiers[0].source.as_deref(), Some("ORCID"));
+    assert_eq!(
+        investigator.affiliation_info[0].affiliation.as_deref(),
+        Some("Hanoi Medical University, Hanoi, Vietnam.")
+    );
+
+    assert_eq!(medline.personal_name_subject_list.len(), 1);
+    assert_eq!(medline.personal_name_subject_list[0].last_name.as_deref(), Some("Apgar"));
+    assert_eq!(medline.personal_name_subject_list[0].fore_name.as_deref(), Some("Virginia"));
+
+    assert_eq!(medline.general_notes, vec![GeneralNote {
+        owner: Some("NLM".to_string()),
+        value: Some("Study group members are listed in the appendix.".to_string())
+    }]);
+    assert_eq!(medline.space_flight_missions, vec!["Expedition 42"]);
+    assert_eq!(medline.citation_subsets, vec!["IM"]);
+    assert_eq!(citation(&set, 1).citation_subsets, vec!["AIM", "IM"]);
+}
+
+#[test]
+fn pubmed_book_article_test() {
+    let set = PubmedArticleSet::read(BOOK_FIXTURE).unwrap();
+
+    assert_eq!(set.articles.len(), 2);
+    assert_eq!(set.book_articles().count(), 1);
+    assert_eq!(set.journal_articles().count(), 1);
+    assert!(set.articles[1].as_article().is_some());
+    assert_eq!(set.articles[0].pmid().and_then(|p| p.value.as_deref()), Some("20301451"));
+    assert_eq!(set.articles[1].pmid().and_then(|p| p.value.as_deref()), Some("33243171"));
+
+    let book_article = set.articles[0].as_book_article().unwrap();
+    let document = book_article.book_document.as_ref().unwrap();
+    let book = document.book.as_ref().unwrap();
+
+    assert_eq!(book.book_title.as_deref(), Some("GeneReviews®"));
+    assert_eq!(book.publisher, Some(Publisher {
+        publisher_name: Some("University of Washington, Seattle".to_string()),
+        publisher_location: Some("Seattle (WA)".to_string())
+    }));
+    assert_eq!(book.pub_date.as_ref().and_then(|d| d.year.as_deref()), Some("1993"));
+    assert_eq!(book.medium.as_deref(), Some("Internet"));
+    assert_eq!(book.beginning_date.as_ref().and_then(|d| d.year.as_deref()), Some("1993"));
+    assert_eq!(book.ending_date.as_ref().and_then(|d| d.year.as_deref()), Some("2021"));
+    assert_eq!(book.investigator_list[0].last_name.as_deref(), Some("Pagon"));
+    assert_eq!(book.volume_titles, vec!["Hereditary Disorders"]);
+    assert_eq!(book.elocation_ids[0].value.as_deref(), Some("10.0000/genereviews"));
+    let editors: Vec<_> = book.editors().iter().filter_map(|a| a.last_name.as_deref()).collect();
+    assert_eq!(editors, vec!["Adam", "Ardinger"]);
+
+    assert_eq!(document.location_labels, vec![LocationLabel {
+        label_type: Some("chapter".to_string()),
+        value: Some("Pre-eclampsia Susceptibility".to_string())
+    }]);
+    assert_eq!(document.article_title.as_deref(), Some("Pre-eclampsia Susceptibility"));
+    assert_eq!(document.languages, vec!["eng"]);
+    assert_eq!(document.author_lists[0].list_type.as_deref(), Some("authors"));
+    assert_eq!(document.publication_type_list[0].value.as_deref(), Some("Review"));
+    assert_eq!(document.abstract_text.as_ref().unwrap().text.len(), 1);
+
+    assert_eq!(document.sections.len(), 2);
+    assert_eq!(document.sections[1].section_title.as_deref(), Some("Diagnosis"));
+    let criteria = &document.sections[1].sections[0];
+    assert_eq!(criteria.section_title.as_deref(), Some("Criteria for FLT1 testing"));
+    assert_eq!(criteria.location_label.as_ref().and_then(|l| l.value.as_deref()), Some("2.1"));
+
+    assert_eq!(document.contribution_date.as_ref().and_then(|d| d.year.as_deref()), Some("2008"));
+    assert_eq!(document.date_revised.as_ref().and_then(|d| d.month.as_deref()), Some("06"));
+    assert_eq!(document.investigator_list[0].last_name.as_deref(), Some("Garovic"));
+    assert_eq!(document.citation_string.as_deref(), Some("Williams PJ. Pre-eclampsia Susceptibility. GeneReviews. 2020."));
+    assert_eq!(document.item_lists, vec![ItemList {
+        list_type: Some("Synonyms".to_string()),
+        items: vec!["Preeclampsia".to_string(), "Toxemia of pregnancy".to_string()]
+    }]);
+
+    let book_data = book_article.pubmed_book_data.as_ref().unwrap();
+    assert_eq!(book_data.publication_status.as_deref(), Some("ppublish"));
+    assert_eq!(book_data.history.len(), 1);
+}
+
+#[test]
+fn delete_citation_test() {
+    let set = PubmedArticleSet::read(include_str!("fixtures/pubmed_update.xml")).unwrap();
+
+    assert_eq!(set.articles.len(), 1);
+    assert_eq!(set.deleted_citations.len(), 3);
+    assert_eq!(set.deleted_citations[1], PMID {
+        version: Some("2".to_string()),
+        value: Some("30119477".to_string())
+    });
+    assert!(set.is_deleted("30119478"));
+    assert!(!set.is_deleted("33242850"));
+
+    assert!(PubmedArticleSet::read(FIXTURE).unwrap().deleted_citations.is_empty());
+}
+
+#[test]
+fn nested_reference_list_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let pubmed_data = set.articles[0].as_article().unwrap().pubmed_data.as_ref().unwrap();
+
+    assert_eq!(pubmed_data.reference_lists.len(), 1);
+    let list = &pubmed_data.reference_lists[0];
+    assert_eq!(list.title.as_deref(), Some("References"));
+    assert_eq!(list.references.len(), 2);
+    assert_eq!(list.reference_lists.len(), 1);
+    assert_eq!(list.reference_lists[0].title.as_deref(), Some("Supplementary references"));
+    assert_eq!(list.all_references().len(), 3);
+
+    let first = list.references[0].parsed_citation().unwrap();
+    assert_eq!(first, ParsedCitation {
+        authors: vec!["Redman CW".to_string(), "Sargent IL".to_string()],
+        title: Some("Latest advances in understanding preeclampsia".to_string()),
+        journal: Some("Science".to_string()),
+        year: Some("2005".to_string()),
+        volume: Some("308".to_string()),
+        issue: Some("5728".to_string()),
+        pages: Some("1592-1594".to_string())
+    });
+
+    let report = list.reference_lists[0].references[0].parsed_citation().unwrap();
+    assert_eq!(report.authors, vec!["World Health Organization"]);
+    assert_eq!(report.title.as_deref(), Some("WHO recommendations for prevention and treatment of pre-eclampsia and eclampsia"));
+    assert_eq!(report.journal, None);
+    assert_eq!(report.year.as_deref(), Some("2011"));
+}
+
+#[test]
+fn parse_citation_test() {
+    let parsed = parse_citation("Levine RJ, Maynard SE, Qian C, et al. Does angiogenesis matter? N Engl J Med 2004 Feb 12;350(7):672-83.");
+    assert_eq!(parsed.authors, vec!["Levine RJ", "Maynard SE", "Qian C"]);
+    assert_eq!(parsed.title.as_deref(), Some("Does angiogenesis matter?"));
+    assert_eq!(parsed.journal.as_deref(), Some("N Engl J Med"));
+    assert_eq!(parsed.year.as_deref(), Some("2004"));
+    assert_eq!(parsed.volume.as_deref(), Some("350"));
+    assert_eq!(parsed.issue.as_deref(), Some("7"));
+    assert_eq!(parsed.pages.as_deref(), Some("672-83"));
+
+    let parsed = parse_citation("Personal communication.");
+    assert_eq!(parsed.authors, vec!["Personal communication"]);
+    assert_eq!(parsed.year, None);
+}
+
+fn date(year: u16, month: Option<u8>, day: Option<u8>) -> PartialDate {
+    PartialDate { year, season: None, month, day }
+}
+
+#[test]
+fn pub_date_range_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let pub_date = |i: usize| article(&set, i).journal.as_ref().unwrap()
+        .journal_issue.as_ref().unwrap()
+        .pub_date.as_ref().unwrap();
+
+    let single = pub_date(0).range().unwrap();
+    assert!(single.is_single());
+    assert_eq!(single.start, date(2021, Some(1), Some(15)));
+    assert_eq!(single.precision(), DatePrecision::Day);
+
+    assert_eq!(pub_date(1).medline_date.as_deref(), Some("1998 Dec-1999 Jan"));
+    assert_eq!(pub_date(1).range(), Some(DateRange {
+        start: date(1998, Some(12), None),
+        end: date(1999, Some(1), None)
+    }));
+
+    let books = PubmedArticleSet::read(BOOK_FIXTURE).unwrap();
+    let seasonal = books.journal_articles().next().unwrap()
+        .medline_citation.as_ref().unwrap()
+        .article.as_ref().unwrap()
+        .journal.as_ref().unwrap()
+        .journal_issue.as_ref().unwrap()
+        .pub_date.as_ref().unwrap();
+    assert_eq!(seasonal.season.as_deref(), Some("Winter"));
+    assert_eq!(seasonal.range().unwrap().precision(), DatePrecision::Season);
+}
+
+#[test]
+fn parse_medline_date_test() {
+    let range = parse_medline_date("2020 Jan-Feb").unwrap();
+    assert_eq!((range.start, range.end), (date(2020, Some(1), None), date(2020, Some(2), None)));
+
+    let range = parse_medline_date("Winter 2019").unwrap();
+    assert!(range.is_single());
+    assert_eq!(range.start.season, Some(Season::Winter));
+    assert_eq!(range.precision(), DatePrecision::Season);
+
+    let range = parse_medline_date("1999 Dec 12-25").unwrap();
+    assert_eq!((range.start, range.end), (date(1999, Some(12), Some(12)), date(1999, Some(12), Some(25))));
+
+    let range = parse_medline_date("1998 Dec-Jan").unwrap();
+    assert_eq!(range.end, date(1999, Some(1), None));
+
+    let range = parse_medline_date("1998-1999").unwrap();
+    assert_eq!((range.start.year, range.end.year), (1998, 1999));
+    assert_eq!(range.precision(), DatePrecision::Year);
+
+    let range = parse_medline_date("1998-99").unwrap();
+    assert_eq!((range.start.year, range.end.year), (1998, 1999));
+    let range = parse_medline_date("1999-00").unwrap();
+    assert_eq!((range.start.year, range.end.year), (1999, 2000));
+
+    let range = parse_medline_date("Spring-Summer 2000").unwrap();
+    assert_eq!(range.start.year, 2000);
+    assert_eq!(range.end.season, Some(Season::Summer));
+
+    assert_eq!(parse_medline_date("Unknown"), None);
+}
+
+#[test]
+fn history_and_earliest_dates_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let first = set.articles[0].as_article().unwrap();
+    let pubmed_data = first.pubmed_data.as_ref().unwrap();
+
+    assert_eq!(pubmed_data.entrez_date(), Some(date(2020, Some(11), Some(27))));
+    assert_eq!(pubmed_data.pubmed_date(), Some(date(2020, Some(11), Some(28))));
+    assert_eq!(pubmed_data.medline_date(), Some(date(2021, Some(1), Some(14))));
+    assert_eq!(pubmed_data.history_date("received"), Some(date(2020, Some(8), Some(14))));
+    assert_eq!(pubmed_data.history_date("revised"), None);
+
+    assert_eq!(first.earliest_publication_date(), Some(date(2020, Some(11), Some(21))));
+    assert_eq!(set.articles[1].as_article().unwrap().earliest_publication_date(), Some(date(1998, Some(12), None)));
+
+    assert_eq!(PartialDate::from_parts(Some("2020"), Some("Mar"), Some("03")), PartialDate::from_parts(Some("2020"), Some("03"), Some("3")));
+    assert!(date(2020, None, None) < date(2020, Some(1), None));
+    assert!(date(2020, Some(1), Some(31)) < date(2020, Some(2), None));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn naive_date_test() {
+    use chrono::NaiveDate;
+
+    assert_eq!(date(2020, Some(11), Some(21)).to_naive_date(), NaiveDate::from_ymd_opt(2020, 11, 21));
+    assert_eq!(date(1998, Some(12), None).to_naive_date(), NaiveDate::from_ymd_opt(1998, 12, 1));
+    assert_eq!(parse_medline_date("Summer 2000").unwrap().start.to_naive_date(), NaiveDate::from_ymd_opt(2000, 6, 1));
+    assert_eq!(date(2021, Some(2), Some(30)).to_naive_date(), None);
+}
+
+#[test]
+fn inline_markup_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+    let first = article(&set, 0);
+
+    assert_eq!(first.title.as_deref(), Some("Expression of FLT1 in the placenta of women with pre-eclampsia and CO2 retention."));
+    let title = first.title_markup.as_ref().unwrap();
+    assert_eq!(title.0[1], Inline::Italic(vec![Inline::Text("FLT1".to_string())]));
+    assert_eq!(title.to_html(), "Expression of <i>FLT1</i> in the placenta of women with pre-eclampsia and CO<sub>2</sub> retention.");
+    assert_eq!(title.to_markdown(), "Expression of *FLT1* in the placenta of women with pre-eclampsia and CO<sub>2</sub> retention.");
+
+    let methods = &first.abstract_text.as_ref().unwrap().text[1];
+    assert_eq!(methods.value.as_deref(), Some("Serum levels above 103 pg/mL were measured in n2 samples using x+1 units."));
+    assert_eq!(
+        methods.markup.to_html(),
+        "Serum levels above 10<sup>3</sup> pg/mL were measured in <i>n<sup>2</sup></i> samples using \
+              <math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mi>x</mi><mo>+</mo><mn>1</mn></math> units."
+    );
+    assert_eq!(
+        methods.markup.to_markdown(),
+        "Serum levels above 10<sup>3</sup> pg/mL were measured in *n<sup>2</sup>* samples using \
+         <math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mi>x</mi><mo>+</mo><mn>1</mn></math> units."
+    );
+
+    let book = PubmedArticleSet::read(BOOK_FIXTURE).unwrap();
+    let document = book.book_articles().next().unwrap().book_document.as_ref().unwrap();
+    assert_eq!(document.article_title_markup.as_ref().unwrap().to_html(), "Pre-eclampsia Susceptibility");
+}
+
+#[test]
+fn abstract_value_is_plain_text_test() {
+    let xml = "<PubmedArticleSet><PubmedArticle><MedlineCitation><Article><Abstract>\
+               <AbstractText>Smith &amp; Jones found p &lt; 0.05 \"x\".</AbstractText>\
+               </Abstract></Article></MedlineCitation></PubmedArticle></PubmedArticleSet>";
+    let set = PubmedArticleSet::read(xml).unwrap();
+    let text = &article(&set, 0).abstract_text.as_ref().unwrap().text[0];
+
+    assert_eq!(text.value.as_deref(), Some("Smith & Jones found p < 0.05 \"x\"."));
+    assert_eq!(text.markup.to_html(), "Smith &amp; Jones found p &lt; 0.05 &quot;x&quot;.");
+}
+
+#[test]
+fn mixed_content_text_test() {
+    let set = PubmedArticleSet::read(FIXTURE).unwrap();
+
+    assert_eq!(citation(&set, 0).keyword_lists[0].keywords[2].value.as_deref(), Some("sFlt-1 ratio"));
+
+    let references = &set.articles[0].as_article().unwrap()
+        .pubmed_data.as_ref().unwrap()
+        .reference_lists[0].references;
+    assert_eq!(
+        references[1].citation.as_deref(),
+        Some("Levine RJ, Maynard SE, Qian C, et al. Circulating angiogenic factors and the risk of preeclampsia. N Engl J Med. 2004;350(7):672-683.")
+    );
+
+    let parsed = references[1].parsed_citation().unwrap();
+    assert_eq!(parsed.title.as_deref(), Some("Circulating angiogenic factors and the risk of preeclampsia"));
+    assert_eq!(parsed.journal.as_deref(), Some("N Engl J Med"));
+    assert_eq!(parsed.pages.as_deref(), Some("672-683"));
+}
+
+#[test]
+fn read_error_test() {
+    use entrez_rs::errors::{Error, ParsingErrorType, TextPosition};
+
+    match PubmedArticleSet::read("  \n") {
+        Err(Error::ParsingError(ParsingErrorType::MissingRoot)) => (),
+        other => panic!("expected a missing root, got {:?}", other)
+    }
+
+    let ncbi_error = "<?xml version=\"1.0\" ?>\n<eFetchResult>\n\t<ERROR>Empty id list - nothing todo</ERROR>\n</eFetchResult>\n";
+    match PubmedArticleSet::read(ncbi_error) {
+        Err(Error::ParsingError(ParsingErrorType::NCBIError { message, position })) => {
+            assert_eq!(message, "Empty id list - nothing todo");
+            assert_eq!(position, TextPosition { byte: 39, line: 3, column: 2 });
+        },
+        other => panic!("expected an NCBI error, got {:?}", other)
+    }
+
+    match PubmedArticleSet::read("<?xml version=\"1.0\" ?>\n<eSearchResult><Count>0</Count></eSearchResult>") {
+        Err(Error::ParsingError(ParsingErrorType::UnexpectedRoot { expected, found, position })) => {
+            assert_eq!(expected, "PubmedArticleSet");
+            assert_eq!(found, "eSearchResult");
+            assert_eq!((position.byte, position.line, position.column), (23, 2, 1));
+        },
+        other => panic!("expected an unexpected root, got {:?}", other)
+    }
+
+    match PubmedArticleSet::read("<PubmedArticleSet><PubmedArticle></PubmedArticleSet>") {
+        Err(Error::ParsingError(ParsingErrorType::RXML(_))) => (),
+        other => panic!("expected an XML error, got {:?}", other)
+    }
+}
+
+#[test]
+fn parse_options_test() {
+    use entrez_rs::errors::{Error, ParsingErrorType};
+
+    let (set, diagnostics) = PubmedArticleSet::read_with(FIXTURE, ParseOptions::strict()).unwrap();
+    assert_eq!(set, PubmedArticleSet::read(FIXTURE).unwrap());
+    assert!(diagnostics.is_empty());
+
+    let (books, diagnostics) = PubmedArticleSet::read_with(BOOK_FIXTURE, ParseOptions::strict()).unwrap();
+    assert_eq!(books, PubmedArticleSet::read(BOOK_FIXTURE).unwrap());
+    assert!(diagnostics.is_empty());
+
+    let unknown = book_fixture_with_unknown_element();
+    let (books, diagnostics) = PubmedArticleSet::read_with(&unknown, ParseOptions::lenient()).unwrap();
+    assert_eq!(books.articles.len(), 2);
+    assert_eq!(diagnostics, vec![Diagnostic {
+        path: "PubmedArticleSet/PubmedBookArticle/BookDocument/Book/StartingDate".to_string(),
+        count: 1
+    }]);
+
+    match PubmedArticleSet::read_with(&unknown, ParseOptions::strict()) {
+        Err(Error::ParsingError(ParsingErrorType::UnknownElement { path, position })) => {
+            assert_eq!(path, "PubmedArticleSet/PubmedBookArticle/BookDocument/Book/StartingDate");
+            assert_eq!((position.line, position.column), (19, 13));
+        },
+        other => panic!("expected an unknown element, got {:?}", other)
+    }
+
+    let unknown_list_item = FIXTURE.replace("<CitationSubset>IM</CitationSubset>", "<CitationSubset>IM</CitationSubset><GeneSymbolList><Gene>FLT1</Gene></GeneSymbolList>");
+    let (_, diagnostics) = PubmedArticleSet::read_with(&unknown_list_item, ParseOptions::default()).unwrap();
+    assert_eq!(diagnostics, vec![Diagnostic {
+        path: "PubmedArticleSet/PubmedArticle/MedlineCitation/GeneSymbolList/Gene".to_string(),
+        count: 2
+    }]);
+
+    let unknown_deleted = "<PubmedArticleSet><DeleteCitation><PMID Version=\"1\">1</PMID><Note>merged</Note></DeleteCitation></PubmedArticleSet>";
+    let (set, diagnostics) = PubmedArticleSet::read_with(unknown_deleted, ParseOptions::lenient()).unwrap();
+    assert_eq!(set.deleted_citations.len(), 1);
+    assert_eq!(diagnostics, vec![Diagnostic {
+        path: "PubmedArticleSet/DeleteCitation/Note".to_string(),
+        count: 1
+    }]);
+    match PubmedArticleSet::read_with(unknown_deleted, ParseOptions::strict()) {
+        Err(Error::ParsingError(ParsingErrorType::UnknownElement { path, .. })) => {
+            assert_eq!(path, "PubmedArticleSet/DeleteCitation/Note")
+        },
+        other => panic!("expected an unknown element, got {:?}", other)
+    }
+}
+
+#[test]
+fn strict_mode_dtd_elements_test() {
+    let xml = FIXTURE
+        .replacen("</MedlineCitation>", "<NumberOfReferences>42</NumberOfReferences>\
+            <OtherID Source=\"NASA\">97123456</OtherID></MedlineCitation>", 1)
+        .replacen("</History>", "<PubMedPubDate PubStatus=\"entrez\"><Year>2021</Year><Month>1</Month><Day>2</Day>\
+            <Hour>3</Hour><Minute>4</Minute><Second>5</Second></PubMedPubDate></History>", 1)
+        .replacen("</PubmedData>", "<ObjectList><Object Type=\"keyword\"><Param Name=\"value\">eclampsia</Param>\
+            </Object></ObjectList></PubmedData>", 1);
+
+    let (set, diagnostics) = PubmedArticleSet::read_with(&xml, ParseOptions::strict()).unwrap();
+    assert!(diagnostics.is_empty());
+
+    let medline = citation(&set, 0);
+    assert_eq!(medline.number_of_references.as_deref(), Some("42"));
+    assert_eq!(medline.other_ids, vec![OtherID {
+        source: Some("NASA".to_string()),
+        value: Some("97123456".to_string())
+    }]);
+
+    let pubmed_data = set.articles[0].as_article().unwrap().pubmed_data.as_ref().unwrap();
+    assert_eq!(pubmed_data.history.last().unwrap().second.as_deref(), Some("5"));
+    assert_eq!(pubmed_data.object_list, vec![Object {
+        object_type: Some("keyword".to_string()),
+        params: vec![Param {
+            name: Some("value".to_string()),
+            value: Some("eclampsia".to_string())
+        }]
+    }]);
+
+    let unknown_inline = FIXTURE.replacen("<i>FLT1</i>", "<span>FLT1</span>", 1);
+    let (set, diagnostics) = PubmedArticleSet::read_with(&unknown_inline, ParseOptions::lenient()).unwrap();
+    assert_eq!(article(&set, 0).title.as_deref(), Some("Expression of FLT1 in the placenta of women with pre-eclampsia and CO2 retention."));
+    assert_eq!(diagnostics, vec![Diagnostic {
+        path: "PubmedArticleSet/PubmedArticle/MedlineCitation/Article/ArticleTitle/span".to_string(),
+        count: 1
+    }]);
+    assert!(PubmedArticleSet::read_with(&unknown_inline, ParseOptions::strict()).is_err());
+}
+
+#[test]
+fn custom_from_node_with_test() {
+    struct Tag(String);
+
+    impl FromXMLNode for Tag {
+        fn from_node_with(node: &roxmltree::Node, ctx: &mut ParseContext) -> Self {
+            node.children().filter(|n| n.is_element()).for_each(|n| ctx.unknown(&n));
+            Tag(node.tag_name().name().to_string())
+        }
+    }
+
+    let document = roxmltree::Document::parse("<Custom><Child/></Custom>").unwrap();
+    assert_eq!(Tag::from_node(&document.root_element()).0, "Custom");
+
+    let mut ctx = ParseContext::new(ParseOptions::strict());
+    Tag::from_node_with(&document.root_element(), &mut ctx);
+    assert!(ctx.finish().is_err());
+}
+
+#[test]
+fn streaming_reader_matches_dom_test() {
+    let fixtures = [FIXTURE, BOOK_FIXTURE, include_str!("fixtures/pubmed_update.xml")];
+
+    for fixture in fixtures.iter() {
+        let (set, diagnostics) = PubmedArticleSet::read_with(fixture, ParseOptions::lenient()).unwrap();
+
+        let mut reader = PubmedArticleReader::new(fixture.as_bytes());
+        let records = (&mut reader).collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(records, set.articles);
+        assert_eq!(reader.deleted_citations(), &set.deleted_citations[..]);
+        assert_eq!(reader.diagnostics(), &diagnostics[..]);
+    }
+
+    let namespaced = FIXTURE
+        .replace("<PubmedArticleSet>", "<PubmedArticleSet xmlns:mml=\"http://www.w3.org/1998/Math/MathML\">")
+        .replace(" xmlns:mml=\"http://www.w3.org/1998/Math/MathML\"><mml:mi>", "><mml:mi>");
+    let set = PubmedArticleSet::read(&namespaced).unwrap();
+    let records = PubmedArticleReader::new(namespaced.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+
+    assert_eq!(records, set.articles);
+    assert_eq!(set, PubmedArticleSet::read(FIXTURE).unwrap());
+}
+
+#[test]
+fn streaming_reader_errors_test() {
+    use entrez_rs::errors::{Error, ParsingErrorType};
+
+    let mut empty = PubmedArticleReader::new("".as_bytes());
+    match empty.next() {
+        Some(Err(Error::ParsingError(ParsingErrorType::MissingRoot))) => (),
+        other => panic!("expected a missing root, got {:?}", other)
+    }
+    assert!(empty.next().is_none());
+
+    let ncbi_error = "<?xml version=\"1.0\" ?>\n<eFetchResult>\n\t<ERROR>Empty id list - nothing todo</ERROR>\n</eFetchResult>\n";
+    match PubmedArticleReader::new(ncbi_error.as_bytes()).next() {
+        Some(Err(Error::ParsingError(ParsingErrorType::NCBIError { message, .. }))) => {
+            assert_eq!(message, "Empty id list - nothing todo")
+        },
+        other => panic!("expected an NCBI error, got {:?}", other)
+    }
+
+    let truncated = &FIXTURE[..FIXTURE.find("</PubmedArticle>").unwrap()];
+    match PubmedArticleReader::new(truncated.as_bytes()).next() {
+        Some(Err(Error::ParsingError(ParsingErrorType::Xml(quick_xml::Error::UnexpectedEof(_))))) => (),
+        other => panic!("expected an unexpected end of file, got {:?}", other)
+    }
+
+    let unknown = book_fixture_with_unknown_element();
+    let strict = PubmedArticleReader::with_options(unknown.as_bytes(), ParseOptions::strict());
+    let results: Vec<_> = strict.collect();
+    assert_eq!(results.len(), 1);
+    match &results[0] {
+        Err(Error::ParsingError(ParsingErrorType::UnknownElement { path, .. })) => {
+            assert_eq!(path, "PubmedArticleSet/PubmedBookArticle/BookDocument/Book/StartingDate")
+        },
+        other => panic!("expected an unknown element, got {:?}", other)
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_read_test() {
+    let fixtures = [FIXTURE, BOOK_FIXTURE, include_str!("fixtures/pubmed_update.xml")];
+
+    for fixture in fixtures.iter() {
+        assert_eq!(PubmedArticleSet::par_read(fixture).unwrap(), PubmedArticleSet::read(fixture).unwrap());
+        assert_eq!(
+            PubmedArticleSet::par_read_with(fixture, ParseOptions::lenient()).unwrap(),
+            PubmedArticleSet::read_with(fixture, ParseOptions::lenient()).unwrap()
+        );
+    }
+
+    let unknown = book_fixture_with_unknown_element();
+    let start = unknown.find("<PubmedBookArticle>").unwrap();
+    let end = unknown.rfind("</PubmedArticleSet>").unwrap();
+    let many = format!("<PubmedArticleSet>{}</PubmedArticleSet>", unknown[start..end].repeat(300));
+    let (set, diagnostics) = PubmedArticleSet::par_read_with(&many, ParseOptions::lenient()).unwrap();
+    assert_eq!(set.articles.len(), 600);
+    assert_eq!(diagnostics[0].count, 300);
+    assert_eq!((set, diagnostics), PubmedArticleSet::read_with(&many, ParseOptions::lenient()).unwrap());
+
+    // The record starts on line 4 of the file and on line 1 of its chunk.
+    match PubmedArticleSet::par_read_with(&unknown, ParseOptions::strict()) {
+        Err(entrez_rs::errors::Error::ParsingError(entrez_rs::errors::ParsingErrorType::UnknownElement { path, position })) => {
+            assert_eq!(path, "PubmedArticleSet/PubmedBookArticle/BookDocument/Book/StartingDate");
+            assert_eq!((position.line, position.column), (19 - 3, 13));
+        },
+        other => panic!("expected an unknown element, got {:?}", other)
+    }
+
+    let namespaced = FIXTURE
+        .replace("<PubmedArticleSet>", "<PubmedArticleSet xmlns:mml=\"http://www.w3.org/1998/Math/MathML\">")
+        .replace(" xmlns:mml=\"http://www.w3.org/1998/Math/MathML\"><mml:mi>", "><mml:mi>");
+    assert_eq!(PubmedArticleSet::par_read(&namespaced).unwrap(), PubmedArticleSet::read(&namespaced).unwrap());
+
+    let paths = ["pubmed_update.xml", "pubmed_articles.xml", "missing.xml", "pubmed_book_articles.xml"]
+        .iter()
+        .map(|name| std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name))
+        .collect::<Vec<_>>();
+    let sets = PubmedArticleSet::par_read_paths(&paths);
+
+    assert_eq!(sets.len(), 4);
+    assert_eq!(sets[0].as_ref().unwrap(), &PubmedArticleSet::read(fixtures[2]).unwrap());
+    assert_eq!(sets[1].as_ref().unwrap(), &PubmedArticleSet::read(FIXTURE).unwrap());
+    assert!(sets[2].is_err());
+    assert_eq!(sets[3].as_ref().unwrap(), &PubmedArticleSet::read(BOOK_FIXTURE).unwrap());
+
+    let with_diagnostics = PubmedArticleSet::par_read_paths_with(&paths[3..], ParseOptions::lenient());
+    assert_eq!(with_diagnostics[0].as_ref().unwrap(), &PubmedArticleSet::read_with(BOOK_FIXTURE, ParseOptions::lenient()).unwrap());
+}